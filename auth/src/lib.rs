@@ -23,16 +23,30 @@ use source::*;
 use std::error::Error as StdError;
 use std::path::PathBuf;
 
-mod metadata;
+pub mod metadata;
 mod oauth2;
-mod source;
+/// Signers of JWTs and arbitrary bytes, such as service account keys.
+pub mod signer;
+/// The producers of [AccessToken]s used by a [Credential].
+pub mod source;
+#[cfg(test)]
+mod test_utils;
 
 const GOOGLE_APPLICATION_CREDENTIALS_ENV: &str = "GOOGLE_APPLICATION_CREDENTIALS";
+const GOOGLE_CLOUD_QUOTA_PROJECT_ENV: &str = "GOOGLE_CLOUD_QUOTA_PROJECT";
+const GOOGLE_CLOUD_PROJECT_ENV: &str = "GOOGLE_CLOUD_PROJECT";
+const CLOUDSDK_CONFIG_ENV: &str = "CLOUDSDK_CONFIG";
+const CLOUDSDK_CORE_PROJECT_ENV: &str = "CLOUDSDK_CORE_PROJECT";
+const QUOTA_PROJECT_HEADER: &str = "x-goog-user-project";
 const WINDOWS_APPDATA_ENV: &str = "APPDATA";
 const UNIX_HOME_ENV: &str = "HOME";
 const USER_CREDENTIAL_FILE: &str = "application_default_credentials.json";
 const GCLOUD_PATH_PART: &str = "gcloud";
 const CONFIG_PATH_PART: &str = ".config";
+const GCLOUD_ACTIVE_CONFIG_FILE: &str = "active_config";
+const GCLOUD_CONFIGURATIONS_PART: &str = "configurations";
+/// How long before their expiration tokens are refreshed in the background.
+const PROACTIVE_REFRESH_SECS: i64 = 225;
 
 #[derive(Debug)]
 pub struct Error {
@@ -163,12 +177,28 @@ impl AccessToken {
             false
         }
     }
+
+    /// Returns true if the token is close enough to its expiration that a new
+    /// one should be fetched ahead of time.
+    pub(crate) fn needs_refresh(&self) -> bool {
+        match self.expires {
+            Some(expires) => expires - Duration::seconds(PROACTIVE_REFRESH_SECS) <= Utc::now(),
+            None => true,
+        }
+    }
 }
 
 /// Configuration for various authentication flows.
+#[derive(Default)]
 pub struct CredentialConfig {
     /// The scopes that the minted [AccessToken] should have.
     scopes: Vec<String>,
+    /// If true, service account keys mint self-signed JWTs.
+    self_signed_jwt: bool,
+    /// The audience of self-signed JWTs.
+    audience: Option<String>,
+    /// Overrides the quota project of the credential.
+    quota_project_id: Option<String>,
 }
 
 impl CredentialConfig {
@@ -181,12 +211,20 @@ impl CredentialConfig {
 #[derive(Debug)]
 pub struct CredentialConfigBuilder {
     scopes: Vec<String>,
+    self_signed_jwt: bool,
+    audience: Option<String>,
+    quota_project_id: Option<String>,
 }
 
 impl CredentialConfigBuilder {
     /// Instantiates a new builder.
     pub fn new() -> Self {
-        Self { scopes: Vec::new() }
+        Self {
+            scopes: Vec::new(),
+            self_signed_jwt: false,
+            audience: None,
+            quota_project_id: None,
+        }
     }
 
     /// Sets scopes used for credential authorization.
@@ -195,10 +233,48 @@ impl CredentialConfigBuilder {
         self
     }
 
+    /// Enables [self-signed JWTs] for service account keys.
+    ///
+    /// Service account keys sign their own tokens instead of exchanging a JWT
+    /// assertion at the OAuth 2.0 token endpoint, which saves a network round
+    /// trip. Other credential types ignore this setting.
+    ///
+    /// [self-signed JWTs]: https://google.aip.dev/auth/4111
+    pub fn self_signed_jwt(mut self, value: bool) -> Self {
+        self.self_signed_jwt = value;
+        self
+    }
+
+    /// Sets the audience of self-signed JWTs, for example
+    /// `https://secretmanager.googleapis.com/`.
+    ///
+    /// Without an audience, the self-signed JWTs carry the scopes instead.
+    pub fn audience(mut self, value: impl Into<String>) -> Self {
+        self.audience = Some(value.into());
+        self
+    }
+
+    /// Sets the project billed for the requests made with the credential,
+    /// overriding the quota project in the credential file and the
+    /// `GOOGLE_CLOUD_QUOTA_PROJECT` environment variable.
+    pub fn quota_project_id(mut self, value: impl Into<String>) -> Self {
+        self.quota_project_id = Some(value.into());
+        self
+    }
+
     /// Builds a [CredentialConfig].
     pub fn build(self) -> Result<CredentialConfig> {
+        if self.audience.is_some() && !self.self_signed_jwt {
+            return Err(Error::new(
+                "an audience requires self-signed JWTs",
+                ErrorKind::Validation,
+            ));
+        }
         Ok(CredentialConfig {
             scopes: self.scopes,
+            self_signed_jwt: self.self_signed_jwt,
+            audience: self.audience,
+            quota_project_id: self.quota_project_id,
         })
     }
 }
@@ -214,6 +290,7 @@ impl Default for CredentialConfigBuilder {
 #[derive(Clone)]
 pub struct Credential {
     source: Box<dyn Source + Send + Sync>,
+    quota_project_id: Option<String>,
 }
 
 // TODO(codyoss): This is currently needed to make generated code easier to generate. Not
@@ -225,6 +302,7 @@ impl Default for Credential {
     fn default() -> Self {
         Self {
             source: Box::new(NoOpSource {}),
+            quota_project_id: None,
         }
     }
 }
@@ -235,19 +313,122 @@ impl Credential {
         self.source.token().await
     }
 
+    /// Returns the headers that authenticate a request with this credential.
+    ///
+    /// These include an `Authorization` header and, if the credential has a
+    /// quota project, a `x-goog-user-project` header.
+    pub async fn headers(&self) -> Result<http::HeaderMap> {
+        let token = self.source.token().await?;
+        let mut headers = self.source.headers(&token)?;
+        if let Some(project) = &self.quota_project_id {
+            headers.insert(QUOTA_PROJECT_HEADER, source::header_value(project)?);
+        }
+        Ok(headers)
+    }
+
+    /// Returns the project billed for the requests made with this credential,
+    /// if any.
+    pub fn quota_project_id(&self) -> Option<String> {
+        self.quota_project_id
+            .clone()
+            .or_else(|| self.source.quota_project_id())
+    }
+
+    /// Overrides the project billed for the requests made with this
+    /// credential.
+    pub fn with_quota_project_id(mut self, value: impl Into<String>) -> Credential {
+        self.quota_project_id = Some(value.into());
+        self
+    }
+
+    /// Returns the ID of the project associated with this credential.
+    ///
+    /// The project ID is the first one found in:
+    /// 1. The `project_id` of a service account key.
+    /// 2. The quota project of the credential.
+    /// 3. The `GOOGLE_CLOUD_PROJECT` environment variable.
+    /// 4. The gcloud configuration, either the `CLOUDSDK_CORE_PROJECT`
+    ///    environment variable or the `project` of the active configuration.
+    /// 5. The metadata server, when running on Google Cloud.
+    pub async fn project_id(&self) -> Result<String> {
+        if let Some(id) = self.source.project_id() {
+            return Ok(id);
+        }
+        if let Some(id) = self.quota_project_id() {
+            return Ok(id);
+        }
+        let local = local_project_id(
+            non_empty_env(GOOGLE_CLOUD_PROJECT_ENV),
+            non_empty_env(CLOUDSDK_CORE_PROJECT_ENV),
+            Credential::gcloud_config_dir().ok(),
+        );
+        if let Some(id) = local.await {
+            return Ok(id);
+        }
+        let client = metadata::MetadataClient::new()?;
+        if client.is_running_on_gce().await {
+            return client.project_id().await;
+        }
+        Err(Error::new(
+            "unable to determine the project ID",
+            ErrorKind::Environment,
+        ))
+    }
+
     /// Creates a Credential that uses [Application Default Credentials](https://google.aip.dev/auth/4110)
     /// to figure out how a to produce a [AccessToken].
     pub async fn find_default(config: CredentialConfig) -> Result<Credential> {
+        let quota_project_id = config
+            .quota_project_id
+            .clone()
+            .or_else(|| std::env::var(GOOGLE_CLOUD_QUOTA_PROJECT_ENV).ok());
         let base_source = Credential::base_source(config).await?;
-        let refreshed_source = RefresherSource {
-            source: base_source,
-            ..Default::default()
-        };
+        let refreshed_source = RefresherSource::new(base_source);
         Ok(Credential {
             source: Box::new(refreshed_source),
+            quota_project_id,
         })
     }
 
+    /// Creates a Credential from an access token obtained elsewhere, for
+    /// example injected by an outer system.
+    ///
+    /// The credential fails once `expires` has passed, it cannot refresh the
+    /// token. Without an expiration the token is used forever.
+    pub fn from_access_token(
+        token: impl Into<String>,
+        expires: Option<DateTime<Utc>>,
+    ) -> Credential {
+        Credential {
+            source: Box::new(StaticTokenSource::new(token, expires)),
+            quota_project_id: None,
+        }
+    }
+
+    /// Creates a Credential that authenticates requests with an [API key].
+    ///
+    /// The key is sent in a `x-goog-api-key` header by [Credential::headers].
+    /// Note that [Credential::access_token] returns the API key itself.
+    ///
+    /// [API key]: https://cloud.google.com/docs/authentication/api-keys
+    pub fn from_api_key(key: impl Into<String>) -> Credential {
+        Credential {
+            source: Box::new(ApiKeySource::new(key)),
+            quota_project_id: None,
+        }
+    }
+
+    /// Creates a Credential from a [Source], such as an [ImpersonatedSource].
+    ///
+    /// The [AccessToken]s produced by the source are cached until they expire.
+    pub fn from_source(source: Box<dyn Source + Send + Sync>) -> Credential {
+        let refreshed_source = RefresherSource::new(source);
+        Credential {
+            source: Box::new(refreshed_source),
+            quota_project_id: None,
+        }
+    }
+
     /// Finds a Source from which to create tokens.
     async fn base_source(
         config: CredentialConfig,
@@ -280,7 +461,8 @@ impl Credential {
     }
 
     /// Creates a source from a file type credential such as a Service Account
-    /// Key file or a gcloud user credential.
+    /// Key file, a gcloud user credential, an impersonated service account, or
+    /// an external account.
     async fn file_source(
         file_path: PathBuf,
         config: CredentialConfig,
@@ -302,10 +484,26 @@ impl Credential {
                     &contents,
                     ServiceAccountKeySourceConfig {
                         scopes: config.scopes,
+                        self_signed_jwt: config.self_signed_jwt,
+                        audience: config.audience,
                     },
                 )?;
                 Box::new(source)
             }
+            "external_account" => {
+                let source = ExternalAccountSource::from_file_contents(
+                    &contents,
+                    ExternalAccountSourceConfig {
+                        scopes: config.scopes,
+                        ..Default::default()
+                    },
+                )?;
+                Box::new(source)
+            }
+            "impersonated_service_account" => {
+                let source = ImpersonatedSource::from_file_contents(&contents, config.scopes)?;
+                Box::new(source)
+            }
             _ => {
                 return Err(Error::new(
                     format!("unsupported credential type found: {}", file.cred_type),
@@ -318,13 +516,22 @@ impl Credential {
 
     /// Returns the path to a gcloud user credential.
     fn well_known_file() -> Result<PathBuf> {
+        let mut path = Credential::gcloud_config_dir()?;
+        path.push(USER_CREDENTIAL_FILE);
+        Ok(path)
+    }
+
+    /// Returns the gcloud configuration directory.
+    fn gcloud_config_dir() -> Result<PathBuf> {
+        if let Ok(dir) = std::env::var(CLOUDSDK_CONFIG_ENV) {
+            return Ok(dir.into());
+        }
         let mut path = PathBuf::new();
         if cfg!(windows) {
             let appdata = std::env::var(WINDOWS_APPDATA_ENV).map_err(|e| {
                 Error::new_with_error("unable to find APPDATA", e, ErrorKind::Environment)
             })?;
             path.push(appdata);
-            path.push(GCLOUD_PATH_PART);
         } else {
             let home = std::env::var(UNIX_HOME_ENV).map_err(|e| {
                 Error::new_with_error("unable to lookup HOME", e, ErrorKind::Environment)
@@ -334,11 +541,172 @@ impl Credential {
         }
 
         path.push(GCLOUD_PATH_PART);
-        path.push(USER_CREDENTIAL_FILE);
         Ok(path)
     }
 }
 
+/// Returns the project ID configured on the local machine.
+///
+/// The arguments are the values of `GOOGLE_CLOUD_PROJECT`,
+/// `CLOUDSDK_CORE_PROJECT` and the gcloud configuration directory, in order of
+/// precedence.
+async fn local_project_id(
+    google_cloud_project: Option<String>,
+    cloudsdk_core_project: Option<String>,
+    gcloud_config_dir: Option<PathBuf>,
+) -> Option<String> {
+    if let Some(id) = google_cloud_project.or(cloudsdk_core_project) {
+        return Some(id);
+    }
+    gcloud_project_id(gcloud_config_dir?).await
+}
+
+/// Returns the project of the active gcloud configuration in `dir`.
+async fn gcloud_project_id(dir: PathBuf) -> Option<String> {
+    let active = tokio::fs::read_to_string(dir.join(GCLOUD_ACTIVE_CONFIG_FILE))
+        .await
+        .map(|name| name.trim().to_string())
+        .unwrap_or_else(|_| "default".to_string());
+    let config = dir
+        .join(GCLOUD_CONFIGURATIONS_PART)
+        .join(format!("config_{active}"));
+    let contents = tokio::fs::read_to_string(config).await.ok()?;
+    gcloud_config_project(&contents)
+}
+
+/// Returns the value of an environment variable, if it is set and not empty.
+fn non_empty_env(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.is_empty())
+}
+
+/// Finds the `project` property in the `[core]` section of a gcloud
+/// configuration file.
+fn gcloud_config_project(contents: &str) -> Option<String> {
+    let mut core = false;
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            core = line == "[core]";
+            continue;
+        }
+        if !core {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "project" && !value.trim().is_empty() {
+                return Some(value.trim().to_string());
+            }
+        }
+    }
+    None
+}
+
+/// IdToken holds an [OpenID Connect ID token], which services such as Cloud
+/// Run and Cloud Functions use to authenticate requests.
+///
+/// [OpenID Connect ID token]: https://cloud.google.com/docs/authentication/token-types#identity-tokens
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct IdToken {
+    /// The actual token, a JWT.
+    pub value: String,
+    expires: Option<DateTime<Utc>>,
+}
+
+impl IdToken {
+    /// Returns the expiration time of the token, from its `exp` claim.
+    pub fn expires(&self) -> Option<DateTime<Utc>> {
+        self.expires
+    }
+}
+
+/// A [IdToken] producer for a target audience that is automatically refreshed
+/// and can be shared across threads.
+#[derive(Clone)]
+pub struct IdTokenCredential {
+    source: Box<dyn Source + Send + Sync>,
+}
+
+impl IdTokenCredential {
+    /// Fetches a [IdToken], reusing the cached token until it expires.
+    pub async fn id_token(&self) -> Result<IdToken> {
+        let token = self.source.token().await?;
+        Ok(IdToken {
+            value: token.value,
+            expires: token.expires,
+        })
+    }
+
+    /// Creates an IdTokenCredential that uses [Application Default Credentials](https://google.aip.dev/auth/4110)
+    /// to produce [IdToken]s for the target audience.
+    ///
+    /// Service account keys, impersonated service accounts, and the metadata
+    /// service can produce ID tokens. Gcloud user credentials cannot.
+    pub async fn find_default(target_audience: impl Into<String>) -> Result<IdTokenCredential> {
+        let audience = target_audience.into();
+        // 1: Known environment variable.
+        if let Ok(file_name) = std::env::var(GOOGLE_APPLICATION_CREDENTIALS_ENV) {
+            let source = IdTokenCredential::file_source(file_name.into(), audience).await?;
+            return Ok(IdTokenCredential::from_source(source));
+        }
+        // 2: Well-known file.
+        if let Ok(path) = Credential::well_known_file() {
+            if path.exists() {
+                let source = IdTokenCredential::file_source(path, audience).await?;
+                return Ok(IdTokenCredential::from_source(source));
+            }
+        }
+        // 3: Check if in an environment with on Google Cloud
+        if metadata::is_running_on_gce().await {
+            let source = ComputeIdTokenSource::new(audience)?;
+            return Ok(IdTokenCredential::from_source(Box::new(source)));
+        }
+
+        Err(Error::new(
+            "unable to detect default credentials",
+            ErrorKind::Validation,
+        ))
+    }
+
+    /// Creates an IdTokenCredential from a [Source] of ID tokens, such as an
+    /// [ImpersonatedIdTokenSource].
+    ///
+    /// The tokens produced by the source are cached until they expire.
+    pub fn from_source(source: Box<dyn Source + Send + Sync>) -> IdTokenCredential {
+        let refreshed_source = RefresherSource::new(source);
+        IdTokenCredential {
+            source: Box::new(refreshed_source),
+        }
+    }
+
+    /// Creates a source of ID tokens from a Service Account Key file or an
+    /// impersonated service account.
+    async fn file_source(
+        file_path: PathBuf,
+        audience: String,
+    ) -> Result<Box<dyn Source + Send + Sync + 'static>> {
+        let contents = tokio::fs::read(file_path).await.map_err(Error::wrap_io)?;
+        let file: Key = serde_json::from_slice(&contents).map_err(Error::wrap_serialization)?;
+        let source: Box<dyn Source + Send + Sync + 'static> = match file.cred_type {
+            "service_account" => Box::new(ServiceAccountIdTokenSource::from_file_contents(
+                &contents, audience,
+            )?),
+            "impersonated_service_account" => Box::new(
+                ImpersonatedIdTokenSource::from_file_contents(&contents, audience)?,
+            ),
+            _ => {
+                return Err(Error::new(
+                    format!(
+                        "unsupported credential type for ID tokens found: {}",
+                        file.cred_type
+                    ),
+                    ErrorKind::Validation,
+                ));
+            }
+        };
+        Ok(source)
+    }
+}
+
 /// A minimal representation of a file credential to determine its type.
 #[derive(Deserialize)]
 struct Key<'a> {
@@ -348,7 +716,165 @@ struct Key<'a> {
 
 #[cfg(test)]
 mod tests {
-    use crate::Credential;
+    use crate::source::Source;
+    use crate::{AccessToken, Credential, CredentialConfig, ErrorKind, IdTokenCredential, Result};
+
+    #[derive(Clone)]
+    struct QuotaProjectSource;
+
+    #[async_trait::async_trait]
+    impl Source for QuotaProjectSource {
+        async fn token(&self) -> Result<AccessToken> {
+            Ok(AccessToken {
+                value: "test-only-token".to_string(),
+                expires: Some(chrono::Utc::now() + chrono::Duration::seconds(3600)),
+            })
+        }
+
+        fn quota_project_id(&self) -> Option<String> {
+            Some("test-only-file-project".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn credential_headers() -> Result<()> {
+        let cred = Credential::from_source(Box::new(QuotaProjectSource));
+        assert_eq!(
+            cred.quota_project_id().as_deref(),
+            Some("test-only-file-project")
+        );
+        let headers = cred.headers().await?;
+        assert_eq!(headers["authorization"], "Bearer test-only-token");
+        assert_eq!(headers["x-goog-user-project"], "test-only-file-project");
+
+        let cred = cred.with_quota_project_id("test-only-override");
+        assert_eq!(
+            cred.quota_project_id().as_deref(),
+            Some("test-only-override")
+        );
+        let headers = cred.headers().await?;
+        assert_eq!(headers["x-goog-user-project"], "test-only-override");
+        assert_eq!(headers.get_all("x-goog-user-project").iter().count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn credential_config_audience_requires_self_signed_jwt() {
+        let err = CredentialConfig::builder()
+            .audience("https://secretmanager.googleapis.com/")
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+
+        let config = CredentialConfig::builder()
+            .self_signed_jwt(true)
+            .audience("https://secretmanager.googleapis.com/")
+            .build()
+            .unwrap();
+        assert!(config.self_signed_jwt);
+        assert_eq!(
+            config.audience.as_deref(),
+            Some("https://secretmanager.googleapis.com/")
+        );
+    }
+
+    #[tokio::test]
+    async fn credential_from_access_token() -> Result<()> {
+        let expires = chrono::Utc::now() + chrono::Duration::seconds(3600);
+        let cred = Credential::from_access_token("test-only-token", Some(expires));
+        assert_eq!(cred.access_token().await?.value, "test-only-token");
+        let headers = cred.headers().await?;
+        assert_eq!(headers["authorization"], "Bearer test-only-token");
+        assert_eq!(headers.len(), 1);
+
+        let cred = Credential::from_access_token("test-only-token", None);
+        assert_eq!(cred.access_token().await?.value, "test-only-token");
+
+        let expired = chrono::Utc::now() - chrono::Duration::seconds(10);
+        let cred = Credential::from_access_token("test-only-token", Some(expired));
+        let err = cred.headers().await.err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn credential_from_api_key() -> Result<()> {
+        let cred = Credential::from_api_key("test-only-key");
+        let headers = cred.headers().await?;
+        assert_eq!(headers["x-goog-api-key"], "test-only-key");
+        assert!(headers["x-goog-api-key"].is_sensitive());
+        assert!(headers.get("authorization").is_none(), "{headers:?}");
+
+        let cred = cred.with_quota_project_id("test-only-project");
+        let headers = cred.headers().await?;
+        assert_eq!(headers["x-goog-user-project"], "test-only-project");
+        Ok(())
+    }
+
+    #[test]
+    fn gcloud_config_project() {
+        let contents = "[compute]\nproject = wrong\n\n[core]\naccount = test-only@example.com\nproject = test-only-project\n";
+        assert_eq!(
+            super::gcloud_config_project(contents).as_deref(),
+            Some("test-only-project")
+        );
+        assert_eq!(super::gcloud_config_project("[core]\nproject =\n"), None);
+        assert_eq!(super::gcloud_config_project(""), None);
+    }
+
+    #[tokio::test]
+    async fn project_id_order() -> Result<()> {
+        let cred = Credential::from_source(Box::new(QuotaProjectSource));
+        assert_eq!(cred.project_id().await?, "test-only-file-project");
+
+        let dir = std::env::temp_dir().join(format!("project-id-order-{}", std::process::id()));
+        let configs = dir.join("configurations");
+        tokio::fs::create_dir_all(&configs).await.unwrap();
+        tokio::fs::write(dir.join("active_config"), "test\n")
+            .await
+            .unwrap();
+        tokio::fs::write(
+            configs.join("config_test"),
+            "[core]\nproject = test-only-gcloud-project\n",
+        )
+        .await
+        .unwrap();
+
+        let env = || Some("test-only-env-project".to_string());
+        let core = || Some("test-only-core-project".to_string());
+        let from_env = super::local_project_id(env(), core(), Some(dir.clone())).await;
+        let from_core = super::local_project_id(None, core(), Some(dir.clone())).await;
+        let from_gcloud = super::local_project_id(None, None, Some(dir.clone())).await;
+        let missing = super::local_project_id(None, None, Some(dir.join("missing"))).await;
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+
+        assert_eq!(from_env.as_deref(), Some("test-only-env-project"));
+        assert_eq!(from_core.as_deref(), Some("test-only-core-project"));
+        assert_eq!(from_gcloud.as_deref(), Some("test-only-gcloud-project"));
+        assert_eq!(missing, None);
+        assert_eq!(super::local_project_id(None, None, None).await, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn id_token_credential_rejects_user_credentials() {
+        let path =
+            std::env::temp_dir().join(format!("id-token-credential-{}.json", std::process::id()));
+        let contents = serde_json::json!({
+            "type": "authorized_user",
+            "client_id": "test-only-id.apps.googleusercontent.com",
+            "client_secret": "test-only-client-secret",
+            "refresh_token": "test-only-refresh-token",
+        });
+        tokio::fs::write(&path, contents.to_string()).await.unwrap();
+        let err = IdTokenCredential::file_source(path.clone(), "https://example.run.app".into())
+            .await
+            .err()
+            .unwrap();
+        let _ = tokio::fs::remove_file(&path).await;
+        assert_eq!(err.kind(), ErrorKind::Validation);
+    }
 
     #[tokio::main]
     #[test]
@@ -362,6 +888,7 @@ mod tests {
         }
         let cred = Credential::find_default(crate::CredentialConfig {
             scopes: vec!["https://www.googleapis.com/auth/cloud-platform".into()],
+            ..Default::default()
         })
         .await
         .unwrap();
@@ -12,9 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! A client for the Google Cloud [metadata server].
+//!
+//! Code running on Google Cloud, for example on Compute Engine, Cloud Run or
+//! GKE, can query the metadata server for details about its environment.
+//!
+//! [metadata server]: https://cloud.google.com/compute/docs/metadata/overview
+
+use gax::backoff_policy::{BackoffPolicy, ExponentialBackoff, ExponentialBackoffBuilder};
 use http::HeaderMap;
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 use tokio::time::{self, Duration};
 
@@ -24,112 +34,678 @@ const DEFAULT_ACCOUNT: &str = "default";
 const GCE_METADATA_HOST_ENV: &str = "GCE_METADATA_HOST";
 const DEFAULT_GCE_METADATA_HOST: &str = "169.254.169.254";
 const GCE_METADATA_HOST_DNS: &str = "metadata.google.internal";
+const METADATA_FLAVOR_HEADER: &str = "Metadata-Flavor";
+const METADATA_FLAVOR_VALUE: &str = "Google";
+const DEFAULT_DETECTION_TIMEOUT: Duration = Duration::from_secs(5);
 
-// TODO(codyoss): cache a client
-// TODO(codyoss): funcs could take &str or impl Into<String>?
+/// A client for the Google Cloud [metadata server].
+///
+/// The client uses the host in the `GCE_METADATA_HOST` environment variable,
+/// if set, instead of the default metadata server address.
+///
+/// [metadata server]: https://cloud.google.com/compute/docs/metadata/overview
+#[derive(Clone, Debug)]
+pub struct MetadataClient {
+    client: Client,
+    host: String,
+    /// True if the host was configured, either explicitly or with the
+    /// environment variable.
+    host_override: bool,
+    detection_timeout: Duration,
+    retry_timeout: Duration,
+}
 
-// TODO: Create a wrapper for reuse that caches useful values.
-fn new_metadata_client() -> Client {
-    let mut headers = HeaderMap::with_capacity(2);
-    headers.insert("Metadata-Flavor", "Google".parse().unwrap());
-    headers.insert("User-Agent", "gcloud-rust/0.1".parse().unwrap());
-    Client::builder().default_headers(headers).build().unwrap()
+/// A value returned by [MetadataClient::wait_for_change].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct MetadataValue {
+    /// The contents of the value.
+    pub value: String,
+    /// The ETag of the value, used to wait for the next change.
+    pub etag: String,
 }
 
-/// Makes a request to the supplied metadata endpoint.
-#[allow(dead_code)]
-pub async fn get(suffix: impl Into<String>) -> Result<String> {
-    get_with_query::<()>(suffix.into(), None).await
+/// A builder for instantiating a [MetadataClient].
+#[derive(Clone, Debug, Default)]
+pub struct MetadataClientBuilder {
+    host: Option<String>,
+    detection_timeout: Option<Duration>,
+    retry_timeout: Option<Duration>,
 }
 
-async fn get_with_query<T: Serialize + ?Sized>(
-    suffix: String,
-    query: Option<&T>,
-) -> Result<String> {
-    let host = env::var(GCE_METADATA_HOST_ENV)
-        .unwrap_or_else(|_| -> String { String::from(DEFAULT_GCE_METADATA_HOST) });
-    let suffix = suffix.trim_start_matches('/');
-
-    let client = new_metadata_client();
-    let content = backoff::future::retry(backoff::ExponentialBackoff::default(), || async {
-        let url = format!("http://{}/computeMetadata/v1/{}", host, suffix);
-        let req = client.get(url);
-        let req = if let Some(query) = query {
-            req.query(query)
-        } else {
-            req
+impl MetadataClientBuilder {
+    /// Instantiates a new builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the metadata server host, for example `127.0.0.1:8080`. This
+    /// takes precedence over the `GCE_METADATA_HOST` environment variable.
+    pub fn host(mut self, value: impl Into<String>) -> Self {
+        self.host = Some(value.into());
+        self
+    }
+
+    /// Sets how long [MetadataClient::is_running_on_gce] waits for the
+    /// metadata server. Defaults to 5 seconds.
+    pub fn detection_timeout(mut self, value: Duration) -> Self {
+        self.detection_timeout = Some(value);
+        self
+    }
+
+    /// Sets how long requests are retried when the metadata server is
+    /// unavailable. Defaults to 15 minutes.
+    pub fn retry_timeout(mut self, value: Duration) -> Self {
+        self.retry_timeout = Some(value);
+        self
+    }
+
+    /// Builds a [MetadataClient].
+    pub fn build(self) -> Result<MetadataClient> {
+        let env_host = env::var(GCE_METADATA_HOST_ENV).ok();
+        self.build_with_env_host(env_host)
+    }
+
+    /// Builds a [MetadataClient], using `env_host` as the value of the
+    /// `GCE_METADATA_HOST` environment variable.
+    pub(crate) fn build_with_env_host(self, env_host: Option<String>) -> Result<MetadataClient> {
+        let mut headers = HeaderMap::with_capacity(2);
+        headers.insert(
+            METADATA_FLAVOR_HEADER,
+            http::HeaderValue::from_static(METADATA_FLAVOR_VALUE),
+        );
+        headers.insert(
+            http::header::USER_AGENT,
+            http::HeaderValue::from_static("gcloud-rust/0.1"),
+        );
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .map_err(Error::wrap_http)?;
+        let host = self.host.or(env_host);
+        Ok(MetadataClient {
+            client,
+            host_override: host.is_some(),
+            host: host.unwrap_or_else(|| DEFAULT_GCE_METADATA_HOST.to_string()),
+            detection_timeout: self.detection_timeout.unwrap_or(DEFAULT_DETECTION_TIMEOUT),
+            retry_timeout: self.retry_timeout.unwrap_or(MAX_ELAPSED_TIME),
+        })
+    }
+}
+
+impl MetadataClient {
+    /// Creates a [MetadataClient] with the default settings.
+    pub fn new() -> Result<Self> {
+        MetadataClientBuilder::new().build()
+    }
+
+    /// Returns a builder to configure a [MetadataClient].
+    pub fn builder() -> MetadataClientBuilder {
+        MetadataClientBuilder::new()
+    }
+
+    /// Checks the environment to determine if code is executing in a Google
+    /// Cloud environment.
+    ///
+    /// If the host is configured the metadata server is assumed to be
+    /// available. Otherwise this pings the metadata server and resolves its
+    /// DNS name, waiting at most the detection timeout.
+    pub async fn is_running_on_gce(&self) -> bool {
+        // If a user explicitly provides a host to talk to the metadata
+        // service we trust them.
+        if self.host_override {
+            return true;
+        }
+        let ping = async {
+            let res = self
+                .client
+                .get(format!("http://{DEFAULT_GCE_METADATA_HOST}"))
+                .send()
+                .await;
+            res.is_ok_and(|r| {
+                r.headers()
+                    .get(METADATA_FLAVOR_HEADER)
+                    .is_some_and(|v| v == METADATA_FLAVOR_VALUE)
+            })
+        };
+        let dns = async {
+            tokio::net::lookup_host((GCE_METADATA_HOST_DNS, 80))
+                .await
+                .is_ok_and(|mut addrs| addrs.next().is_some())
         };
-        let res = req.send().await.map_err(Error::wrap_http)?;
+
+        // Race pinging the metadata service by IP and DNS. Depending on the
+        // environment different requests return faster.
+        let detect = async {
+            tokio::pin!(ping, dns);
+            let (mut ping_done, mut dns_done) = (false, false);
+            loop {
+                tokio::select! {
+                    found = &mut ping, if !ping_done => {
+                        if found {
+                            return true;
+                        }
+                        ping_done = true;
+                    }
+                    found = &mut dns, if !dns_done => {
+                        if found {
+                            return true;
+                        }
+                        dns_done = true;
+                    }
+                    else => return false,
+                }
+            }
+        };
+        time::timeout(self.detection_timeout, detect)
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Makes a request to the supplied metadata endpoint, for example
+    /// `instance/hostname`.
+    pub async fn get(&self, suffix: &str) -> Result<String> {
+        self.get_with_query::<()>(suffix, None).await
+    }
+
+    /// Makes a recursive request to the supplied metadata directory, for
+    /// example `instance/attributes/`, and parses the JSON response.
+    pub async fn get_recursive<T: DeserializeOwned>(&self, suffix: &str) -> Result<T> {
+        let json = self
+            .get_with_query(suffix, Some(&[("recursive", "true")]))
+            .await?;
+        serde_json::from_str(&json).map_err(Error::wrap_serialization)
+    }
+
+    /// Waits until the supplied metadata value changes, or `timeout` passes.
+    ///
+    /// Without `last_etag` this waits for any change from the current value.
+    /// Use the [MetadataValue::etag] of the result to wait for the next change.
+    pub async fn wait_for_change(
+        &self,
+        suffix: &str,
+        last_etag: Option<&str>,
+        timeout: Duration,
+    ) -> Result<MetadataValue> {
+        let mut query = vec![
+            ("wait_for_change", "true".to_string()),
+            ("timeout_sec", timeout.as_secs().max(1).to_string()),
+        ];
+        if let Some(etag) = last_etag {
+            query.push(("last_etag", etag.to_string()));
+        }
+        let res = self
+            .client
+            .get(self.url(suffix))
+            .query(&query)
+            // Give the metadata server some time to respond after the timeout.
+            .timeout(timeout + Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(Error::wrap_http)?;
         if !res.status().is_success() {
-            return Err(backoff::Error::transient(Error::new(
+            return Err(Error::new(
                 format!("bad request with status: {}", res.status().as_str()),
-                crate::ErrorKind::Http,
-            )));
+                ErrorKind::Http,
+            ));
+        }
+        let etag = res
+            .headers()
+            .get(http::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let value = res.text().await.map_err(Error::wrap_http)?;
+        Ok(MetadataValue { value, etag })
+    }
+
+    /// Returns the ID of the project running the code.
+    pub async fn project_id(&self) -> Result<String> {
+        self.get_trimmed("project/project-id").await
+    }
+
+    /// Returns the numeric ID of the project running the code.
+    pub async fn numeric_project_id(&self) -> Result<String> {
+        self.get_trimmed("project/numeric-project-id").await
+    }
+
+    /// Returns the zone of the instance, for example `us-central1-a`.
+    pub async fn zone(&self) -> Result<String> {
+        // The value is formatted as `projects/{number}/zones/{zone}`.
+        let zone = self.get_trimmed("instance/zone").await?;
+        Ok(zone.rsplit('/').next().unwrap_or_default().to_string())
+    }
+
+    /// Returns the value of a custom instance attribute.
+    pub async fn instance_attribute(&self, name: &str) -> Result<String> {
+        self.get(&format!("instance/attributes/{name}")).await
+    }
+
+    /// Returns all the custom instance attributes.
+    pub async fn instance_attributes(&self) -> Result<HashMap<String, String>> {
+        self.get_recursive("instance/attributes/").await
+    }
+
+    /// Returns the value of a custom project attribute.
+    pub async fn project_attribute(&self, name: &str) -> Result<String> {
+        self.get(&format!("project/attributes/{name}")).await
+    }
+
+    /// Returns the email of a service account attached to the instance. If an
+    /// account is not provided the value will be set to `default`.
+    pub async fn service_account_email(&self, account: Option<&str>) -> Result<String> {
+        let account = account.unwrap_or(DEFAULT_ACCOUNT);
+        self.get_trimmed(&format!("instance/service-accounts/{account}/email"))
+            .await
+    }
+
+    /// Returns the scopes of a service account attached to the instance. If
+    /// an account is not provided the value will be set to `default`.
+    pub async fn scopes(&self, account: Option<&str>) -> Result<Vec<String>> {
+        let account = account.unwrap_or(DEFAULT_ACCOUNT);
+        let scopes = self
+            .get(&format!("instance/service-accounts/{account}/scopes"))
+            .await?;
+        Ok(scopes
+            .lines()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Fetches a [Token] from the metadata service with the provided scopes.
+    /// If an account is not provided the value will be set to `default`.
+    pub(crate) async fn access_token(
+        &self,
+        account: Option<&str>,
+        scopes: Vec<String>,
+    ) -> Result<Token> {
+        if scopes.is_empty() {
+            return Err(Error::new("scopes must be provided", ErrorKind::Validation));
+        }
+        if !self.is_running_on_gce().await {
+            return Err(Error::new(
+                "can't get token from metadata service, not running on GCE",
+                ErrorKind::Validation,
+            ));
         }
-        let content = res.text().await.map_err(Error::wrap_http)?;
-        Ok(content)
-    })
-    .await?;
-    Ok(content)
+        let account = account.unwrap_or(DEFAULT_ACCOUNT);
+        let suffix = format!("instance/service-accounts/{}/token", account);
+        let query = &[("scopes", scopes.join(","))];
+        let json = self.get_with_query(&suffix, Some(query)).await?;
+        let token_response: Token =
+            serde_json::from_str(json.as_str()).map_err(Error::wrap_serialization)?;
+        if token_response.expires_in == 0 || token_response.access_token.is_empty() {
+            return Err(Error::new(
+                "incomplete token received from metadata",
+                ErrorKind::Validation,
+            ));
+        }
+        Ok(token_response)
+    }
+
+    /// Fetches an ID token from the metadata service for the provided
+    /// audience. The token includes the service account email and the
+    /// instance details.
+    pub(crate) async fn id_token(&self, account: Option<&str>, audience: &str) -> Result<String> {
+        if audience.is_empty() {
+            return Err(Error::new(
+                "audience must be provided",
+                ErrorKind::Validation,
+            ));
+        }
+        if !self.is_running_on_gce().await {
+            return Err(Error::new(
+                "can't get token from metadata service, not running on GCE",
+                ErrorKind::Validation,
+            ));
+        }
+        let account = account.unwrap_or(DEFAULT_ACCOUNT);
+        let suffix = format!("instance/service-accounts/{}/identity", account);
+        let query = &[("audience", audience), ("format", "full")];
+        let token = self.get_with_query(&suffix, Some(query)).await?;
+        if token.is_empty() {
+            return Err(Error::new(
+                "empty token received from metadata",
+                ErrorKind::Validation,
+            ));
+        }
+        Ok(token)
+    }
+
+    async fn get_trimmed(&self, suffix: &str) -> Result<String> {
+        Ok(self.get(suffix).await?.trim().to_string())
+    }
+
+    fn url(&self, suffix: &str) -> String {
+        let suffix = suffix.trim_start_matches('/');
+        format!("http://{}/computeMetadata/v1/{}", self.host, suffix)
+    }
+
+    async fn get_with_query<T: Serialize + ?Sized>(
+        &self,
+        suffix: &str,
+        query: Option<&T>,
+    ) -> Result<String> {
+        let url = self.url(suffix);
+        retry_with_backoff(&metadata_backoff_policy(), self.retry_timeout, || async {
+            let req = self.client.get(url.as_str());
+            let req = if let Some(query) = query {
+                req.query(query)
+            } else {
+                req
+            };
+            let res = req.send().await.map_err(Error::wrap_http)?;
+            let status = res.status();
+            if !status.is_success() {
+                let error = Error::new(
+                    format!("bad request with status: {}", status.as_str()),
+                    crate::ErrorKind::Http,
+                );
+                // Only server errors and throttling are worth retrying, the
+                // value does not exist otherwise.
+                if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+                    return Ok(Attempt::Transient(error));
+                }
+                return Err(error);
+            }
+            let content = res.text().await.map_err(Error::wrap_http)?;
+            Ok(Attempt::Done(content))
+        })
+        .await
+    }
+}
+
+/// The stop condition for requests to the metadata service.
+const MAX_ELAPSED_TIME: Duration = Duration::from_secs(15 * 60);
+
+/// The backoff policy for requests to the metadata service.
+fn metadata_backoff_policy() -> ExponentialBackoff {
+    ExponentialBackoffBuilder::new()
+        .set_initial_delay(Duration::from_millis(500))
+        .set_maximum_delay(Duration::from_secs(60))
+        .set_scaling(1.5)
+        .build()
+        .expect("the metadata backoff policy settings are valid")
+}
+
+/// The outcome of a single attempt in [retry_with_backoff].
+enum Attempt<T> {
+    /// The attempt succeeded.
+    Done(T),
+    /// The attempt failed, but may succeed if retried.
+    Transient(Error),
+}
+
+/// Calls `attempt` until it succeeds, fails with a permanent error, or
+/// `max_elapsed` time has passed. The delay between attempts is determined by
+/// `backoff`.
+async fn retry_with_backoff<F, Fut, T>(
+    backoff: &dyn BackoffPolicy,
+    max_elapsed: Duration,
+    attempt: F,
+) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<Attempt<T>>>,
+{
+    let loop_start = time::Instant::now();
+    let mut attempt_count = 0;
+    let mut delay = Duration::ZERO;
+    loop {
+        attempt_count += 1;
+        let error = match attempt().await? {
+            Attempt::Done(value) => return Ok(value),
+            Attempt::Transient(e) => e,
+        };
+        delay = backoff.on_failure(loop_start.into_std(), attempt_count, delay);
+        if loop_start.elapsed() + delay > max_elapsed {
+            return Err(error);
+        }
+        time::sleep(delay).await;
+    }
 }
 
 /// Checks the environment to determine if code is executing in a Google Cloud
 /// environment.
-pub async fn is_running_on_gce() -> bool {
-    // If a user explicitly provides env var to talk to the metadata service we
-    // trust them.
-    if std::env::var(GCE_METADATA_HOST_ENV).is_ok() {
-        return true;
-    }
-    let client = new_metadata_client();
-    let res1 = client.get("http://169.254.169.254").send();
-    let res2 = tokio::net::TcpListener::bind((GCE_METADATA_HOST_DNS, 0));
-
-    // Race pinging the metadata service by IP and DNS. Depending on the
-    // environment different requests return faster. In the future we should
-    // check for more env vars.
-    let check = tokio::select! {
-        _ = time::sleep(Duration::from_secs(5)) => false,
-        _ = res1 => true,
-        _ = res2 => true,
-    };
-    if check {
-        return true;
-    }
-
-    false
+pub(crate) async fn is_running_on_gce() -> bool {
+    match MetadataClient::new() {
+        Ok(client) => client.is_running_on_gce().await,
+        Err(_) => false,
+    }
 }
 
 /// The result of requesting a token from the metadata service.
 #[derive(Deserialize)]
-pub struct Token {
+pub(crate) struct Token {
     pub access_token: String,
     pub expires_in: i64,
 }
 
-/// Fetches a [AccessToken] from the metadata service with the provided scopes. If an
+/// Fetches a [Token] from the metadata service with the provided scopes. If an
 /// account is not provided the value will be set to `default`.
-pub async fn fetch_access_token(account: Option<&str>, scopes: Vec<String>) -> Result<Token> {
-    if scopes.is_empty() {
-        return Err(Error::new("scopes must be provided", ErrorKind::Validation));
-    }
-    if !is_running_on_gce().await {
-        return Err(Error::new(
-            "can't get token from metadata service, not running on GCE",
-            ErrorKind::Validation,
-        ));
-    }
-    let account = account.unwrap_or(DEFAULT_ACCOUNT);
-    let suffix = format!("instance/service-accounts/{}/token", account);
-    let query = &[("scopes", scopes.join(","))];
-    let json = get_with_query(suffix, Some(query)).await?;
-    let token_response: Token =
-        serde_json::from_str(json.as_str()).map_err(Error::wrap_serialization)?;
-    if token_response.expires_in == 0 || token_response.access_token.is_empty() {
-        return Err(Error::new(
-            "incomplete token received from metadata",
-            ErrorKind::Validation,
-        ));
-    }
-    Ok(token_response)
+pub(crate) async fn fetch_access_token(
+    account: Option<&str>,
+    scopes: Vec<String>,
+) -> Result<Token> {
+    MetadataClient::new()?.access_token(account, scopes).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::{Path, Query};
+    use std::sync::Mutex;
+
+    /// Starts a fake metadata server, returning its host.
+    async fn start_fake() -> String {
+        let handler = |Path(path): Path<String>,
+                       Query(query): Query<HashMap<String, String>>,
+                       headers: axum::http::HeaderMap| async move {
+            use axum::http::StatusCode;
+            use axum::response::IntoResponse;
+            assert_eq!(headers["metadata-flavor"], "Google");
+            let recursive = query.get("recursive").map(String::as_str) == Some("true");
+            match path.as_str() {
+                "project/project-id" => "test-only-project".into_response(),
+                "project/numeric-project-id" => "123456".into_response(),
+                "project/attributes/ssh-keys" => "test-only-keys".into_response(),
+                "instance/zone" => "projects/123456/zones/us-central1-a".into_response(),
+                "instance/attributes/" if recursive => {
+                    r#"{"env":"test","role":"web"}"#.into_response()
+                }
+                "instance/attributes/env" if query.contains_key("wait_for_change") => {
+                    assert_eq!(query["timeout_sec"], "30");
+                    let etag = match query.get("last_etag").map(String::as_str) {
+                        Some("etag-1") => "etag-2",
+                        _ => "etag-1",
+                    };
+                    ([("etag", etag)], format!("value-{etag}")).into_response()
+                }
+                "instance/attributes/env" => "test".into_response(),
+                "instance/service-accounts/default/email" => {
+                    "test-only@example.com\n".into_response()
+                }
+                "instance/service-accounts/default/scopes" => {
+                    "https://www.googleapis.com/auth/cloud-platform\nopenid\n".into_response()
+                }
+                _ => StatusCode::NOT_FOUND.into_response(),
+            }
+        };
+        let app =
+            axum::Router::new().route("/computeMetadata/v1/{*path}", axum::routing::get(handler));
+        crate::test_utils::serve(app).await
+    }
+
+    #[tokio::test]
+    async fn client_values() -> Result<()> {
+        let client = MetadataClient::builder().host(start_fake().await).build()?;
+        assert!(client.is_running_on_gce().await);
+        assert_eq!(client.project_id().await?, "test-only-project");
+        assert_eq!(client.numeric_project_id().await?, "123456");
+        assert_eq!(client.zone().await?, "us-central1-a");
+        assert_eq!(client.instance_attribute("env").await?, "test");
+        assert_eq!(
+            client.instance_attributes().await?,
+            HashMap::from([
+                ("env".to_string(), "test".to_string()),
+                ("role".to_string(), "web".to_string())
+            ])
+        );
+        assert_eq!(
+            client.project_attribute("ssh-keys").await?,
+            "test-only-keys"
+        );
+        assert_eq!(
+            client.service_account_email(None).await?,
+            "test-only@example.com"
+        );
+        assert_eq!(
+            client.scopes(None).await?,
+            vec!["https://www.googleapis.com/auth/cloud-platform", "openid"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_not_found_is_permanent() -> Result<()> {
+        let client = MetadataClient::builder().host(start_fake().await).build()?;
+        let err = client.instance_attribute("missing").await.err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Http);
+        assert!(err.to_string().contains("404"), "{err}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_wait_for_change() -> Result<()> {
+        let client = MetadataClient::builder().host(start_fake().await).build()?;
+        let timeout = Duration::from_secs(30);
+        let first = client
+            .wait_for_change("instance/attributes/env", None, timeout)
+            .await?;
+        assert_eq!(first.value, "value-etag-1");
+        assert_eq!(first.etag, "etag-1");
+        let next = client
+            .wait_for_change("instance/attributes/env", Some(&first.etag), timeout)
+            .await?;
+        assert_eq!(next.value, "value-etag-2");
+        assert_eq!(next.etag, "etag-2");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_uses_host_env() -> Result<()> {
+        let host = start_fake().await;
+        let client = MetadataClient::builder().build_with_env_host(Some(host.clone()))?;
+        assert!(client.is_running_on_gce().await);
+        assert_eq!(client.project_id().await?, "test-only-project");
+
+        // The builder host takes precedence over the environment variable.
+        let client = MetadataClient::builder()
+            .host(host)
+            .build_with_env_host(Some("127.0.0.1:1".to_string()))?;
+        assert_eq!(client.project_id().await?, "test-only-project");
+
+        let client = MetadataClient::builder().build_with_env_host(None)?;
+        assert_eq!(client.host, DEFAULT_GCE_METADATA_HOST);
+        assert!(!client.host_override);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_detection_timeout() -> Result<()> {
+        let client = MetadataClient::builder()
+            .detection_timeout(Duration::from_millis(200))
+            .build()?;
+        let start = std::time::Instant::now();
+        // The result depends on where the test runs, only the time is checked.
+        let _ = client.is_running_on_gce().await;
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "{:?}",
+            start.elapsed()
+        );
+        Ok(())
+    }
+
+    #[derive(Debug)]
+    struct ConstantBackoff(Duration);
+
+    impl BackoffPolicy for ConstantBackoff {
+        fn on_failure(&self, _: std::time::Instant, _: u32, _: Duration) -> Duration {
+            self.0
+        }
+    }
+
+    fn transient() -> Error {
+        Error::new("transient", ErrorKind::Http)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_with_backoff_success() -> Result<()> {
+        let start = time::Instant::now();
+        let attempts = Mutex::new(0);
+        let got = retry_with_backoff(
+            &ConstantBackoff(Duration::from_secs(2)),
+            Duration::from_secs(60),
+            || {
+                let mut count = attempts.lock().unwrap();
+                *count += 1;
+                let result = if *count < 3 {
+                    Attempt::Transient(transient())
+                } else {
+                    Attempt::Done(*count)
+                };
+                async move { Ok(result) }
+            },
+        )
+        .await?;
+        assert_eq!(got, 3);
+        assert_eq!(start.elapsed(), Duration::from_secs(4));
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_with_backoff_permanent() {
+        let attempts = Mutex::new(0);
+        let got = retry_with_backoff(
+            &ConstantBackoff(Duration::from_secs(2)),
+            Duration::from_secs(60),
+            || {
+                *attempts.lock().unwrap() += 1;
+                async { Err::<Attempt<()>, _>(Error::new("permanent", ErrorKind::Validation)) }
+            },
+        )
+        .await;
+        let err = got.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+        assert_eq!(*attempts.lock().unwrap(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_with_backoff_max_elapsed() {
+        let start = time::Instant::now();
+        let attempts = Mutex::new(0);
+        let got = retry_with_backoff(
+            &ConstantBackoff(Duration::from_secs(10)),
+            Duration::from_secs(35),
+            || {
+                *attempts.lock().unwrap() += 1;
+                async { Ok(Attempt::<()>::Transient(transient())) }
+            },
+        )
+        .await;
+        let err = got.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Http);
+        assert_eq!(*attempts.lock().unwrap(), 4);
+        assert_eq!(start.elapsed(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn backoff_policy() {
+        let policy = metadata_backoff_policy();
+        let got = policy.on_failure(std::time::Instant::now(), 1, Duration::ZERO);
+        assert!(got <= Duration::from_millis(500), "{got:?}");
+    }
 }
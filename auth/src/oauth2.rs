@@ -22,13 +22,16 @@ pub struct JwsClaims<'a> {
     pub iss: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scope: Option<&'a str>,
-    pub aud: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aud: Option<&'a str>,
     pub exp: Option<i64>,
     pub iat: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub typ: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sub: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_audience: Option<&'a str>,
 }
 
 impl JwsClaims<'_> {
@@ -36,7 +39,7 @@ impl JwsClaims<'_> {
         let now = Utc::now() - chrono::Duration::seconds(10);
         self.iat = self.iat.or_else(|| Some(now.timestamp()));
         self.exp = self
-            .iat
+            .exp
             .or_else(|| Some((now + chrono::Duration::hours(1)).timestamp()));
         if self.exp.unwrap() < self.iat.unwrap() {
             return Err(Error::new(
@@ -0,0 +1,955 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Workload and workforce identity federation, see [AIP-4117].
+//!
+//! [AIP-4117]: https://google.aip.dev/auth/4117
+
+use super::{ImpersonatedSource, Source, CLOUD_PLATFORM_SCOPE, DEFAULT_IMPERSONATION_LIFETIME};
+use crate::{AccessToken, Error, ErrorKind, Result};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+const TOKEN_EXCHANGE_GRANT: &str = "urn:ietf:params:oauth:grant-type:token-exchange";
+const ACCESS_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:access_token";
+const SAML2_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:saml2";
+const DEFAULT_STS_ENDPOINT: &str = "https://sts.googleapis.com/v1/token";
+const ALLOW_EXECUTABLES_ENV: &str = "GOOGLE_EXTERNAL_ACCOUNT_ALLOW_EXECUTABLES";
+const DEFAULT_EXECUTABLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+const MIN_EXECUTABLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+const MAX_EXECUTABLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
+/// Configuration for building an [ExternalAccountSource].
+#[derive(Clone, Default)]
+pub struct ExternalAccountSourceConfig {
+    /// The scopes that the minted [AccessToken] should have.
+    pub scopes: Vec<String>,
+    /// Overrides the token exchange endpoint in the credential file.
+    pub token_url: Option<String>,
+}
+
+/// A [Source] derived from an external account credential file, also known as
+/// workload or workforce identity federation.
+///
+/// A subject token is read from a file, a URL, or the output of an executable
+/// and exchanged for a Google access token by the Security Token Service. If
+/// the file configures service account impersonation, the exchanged token is
+/// used to impersonate the service account.
+#[derive(Clone)]
+pub struct ExternalAccountSource {
+    source: Box<dyn Source + Send + Sync>,
+    quota_project_id: Option<String>,
+}
+
+/// A representation of an external account credential file.
+#[derive(Clone, Deserialize)]
+struct ExternalAccountFile {
+    audience: String,
+    subject_token_type: String,
+    #[serde(default)]
+    token_url: Option<String>,
+    #[serde(default)]
+    service_account_impersonation_url: Option<String>,
+    #[serde(default)]
+    service_account_impersonation: Option<ImpersonationOptions>,
+    credential_source: CredentialSourceFile,
+    #[serde(default)]
+    client_id: Option<String>,
+    #[serde(default)]
+    client_secret: Option<String>,
+    #[serde(default)]
+    workforce_pool_user_project: Option<String>,
+    #[serde(default)]
+    quota_project_id: Option<String>,
+}
+
+#[derive(Clone, Deserialize)]
+struct ImpersonationOptions {
+    #[serde(default)]
+    token_lifetime_seconds: Option<u64>,
+}
+
+/// The `credential_source` field, only one of `file`, `url` or `executable`
+/// may be set.
+#[derive(Clone, Deserialize)]
+struct CredentialSourceFile {
+    #[serde(default)]
+    file: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    #[serde(default)]
+    executable: Option<ExecutableConfig>,
+    #[serde(default)]
+    format: Option<Format>,
+    #[serde(default)]
+    environment_id: Option<String>,
+}
+
+#[derive(Clone, Deserialize)]
+struct ExecutableConfig {
+    command: String,
+    #[serde(default)]
+    timeout_millis: Option<u64>,
+    #[serde(default)]
+    output_file: Option<String>,
+}
+
+/// The format of file and URL sourced subject tokens.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Format {
+    Text,
+    Json { subject_token_field_name: String },
+}
+
+/// Where to find the subject token.
+#[derive(Clone)]
+enum SubjectTokenSource {
+    File {
+        path: String,
+        format: Format,
+    },
+    Url {
+        url: String,
+        headers: HashMap<String, String>,
+        format: Format,
+    },
+    Executable {
+        command: String,
+        timeout: std::time::Duration,
+        output_file: Option<String>,
+        allow_executables: bool,
+    },
+}
+
+impl ExternalAccountSource {
+    /// Create an [ExternalAccountSource] from a file path.
+    pub async fn from_file(
+        path: impl AsRef<Path>,
+        config: ExternalAccountSourceConfig,
+    ) -> Result<Self> {
+        let contents = tokio::fs::read(path).await.map_err(Error::wrap_io)?;
+        Self::from_file_contents(&contents, config)
+    }
+
+    /// Create an [ExternalAccountSource] from bytes.
+    pub fn from_file_contents(
+        contents: &[u8],
+        config: ExternalAccountSourceConfig,
+    ) -> Result<Self> {
+        let allow_executables = std::env::var(ALLOW_EXECUTABLES_ENV).as_deref() == Ok("1");
+        Self::from_contents(contents, config, allow_executables)
+    }
+
+    fn from_contents(
+        contents: &[u8],
+        config: ExternalAccountSourceConfig,
+        allow_executables: bool,
+    ) -> Result<Self> {
+        if config.scopes.is_empty() {
+            return Err(Error::new("scopes must be provided", ErrorKind::Validation));
+        }
+        let file: ExternalAccountFile =
+            serde_json::from_slice(contents).map_err(Error::wrap_serialization)?;
+        let subject = SubjectTokenSource::new(file.credential_source, allow_executables)?;
+        let token_url = config
+            .token_url
+            .or(file.token_url)
+            .unwrap_or_else(|| DEFAULT_STS_ENDPOINT.to_string());
+        let Some(impersonation_url) = file.service_account_impersonation_url else {
+            let source = StsSource {
+                subject,
+                audience: file.audience,
+                subject_token_type: file.subject_token_type,
+                token_url,
+                scopes: config.scopes,
+                client_id: file.client_id,
+                client_secret: file.client_secret,
+                workforce_pool_user_project: file.workforce_pool_user_project,
+                impersonated_email: None,
+            };
+            return Ok(Self {
+                source: Box::new(source),
+                quota_project_id: file.quota_project_id,
+            });
+        };
+        // The exchanged token is only used to impersonate the service account.
+        let source = StsSource {
+            subject,
+            audience: file.audience,
+            subject_token_type: file.subject_token_type,
+            token_url,
+            scopes: vec![CLOUD_PLATFORM_SCOPE.to_string()],
+            client_id: file.client_id,
+            client_secret: file.client_secret,
+            workforce_pool_user_project: None,
+            impersonated_email: impersonated_email(&impersonation_url),
+        };
+        let lifetime = file
+            .service_account_impersonation
+            .and_then(|o| o.token_lifetime_seconds)
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_IMPERSONATION_LIFETIME);
+        let source = ImpersonatedSource::with_url(
+            Box::new(source),
+            impersonation_url,
+            Vec::new(),
+            config.scopes,
+            lifetime,
+        );
+        Ok(Self {
+            source: Box::new(source),
+            quota_project_id: file.quota_project_id,
+        })
+    }
+}
+
+#[async_trait]
+impl Source for ExternalAccountSource {
+    async fn token(&self) -> Result<AccessToken> {
+        self.source.token().await
+    }
+
+    fn quota_project_id(&self) -> Option<String> {
+        self.quota_project_id.clone()
+    }
+}
+
+/// Returns the email in a `...serviceAccounts/{email}:generateAccessToken` URL.
+fn impersonated_email(url: &str) -> Option<String> {
+    let (_, email) = url.rsplit_once("/serviceAccounts/")?;
+    let email = email.strip_suffix(":generateAccessToken").unwrap_or(email);
+    Some(email.to_string())
+}
+
+impl SubjectTokenSource {
+    fn new(source: CredentialSourceFile, allow_executables: bool) -> Result<Self> {
+        if source.environment_id.is_some() {
+            return Err(Error::new(
+                "AWS sourced external account credentials are not supported",
+                ErrorKind::Validation,
+            ));
+        }
+        let format = source.format.unwrap_or(Format::Text);
+        match (source.file, source.url, source.executable) {
+            (Some(path), None, None) => Ok(Self::File { path, format }),
+            (None, Some(url), None) => Ok(Self::Url {
+                url,
+                headers: source.headers,
+                format,
+            }),
+            (None, None, Some(executable)) => {
+                let timeout = executable
+                    .timeout_millis
+                    .map(std::time::Duration::from_millis)
+                    .unwrap_or(DEFAULT_EXECUTABLE_TIMEOUT);
+                if !(MIN_EXECUTABLE_TIMEOUT..=MAX_EXECUTABLE_TIMEOUT).contains(&timeout) {
+                    return Err(Error::new(
+                        format!("executable timeout must be between {MIN_EXECUTABLE_TIMEOUT:?} and {MAX_EXECUTABLE_TIMEOUT:?}, got {timeout:?}"),
+                        ErrorKind::Validation,
+                    ));
+                }
+                Ok(Self::Executable {
+                    command: executable.command,
+                    timeout,
+                    output_file: executable.output_file,
+                    allow_executables,
+                })
+            }
+            _ => Err(Error::new(
+                "the credential source must have exactly one of file, url or executable",
+                ErrorKind::Validation,
+            )),
+        }
+    }
+
+    /// Retrieves the subject token.
+    async fn subject_token(&self, sts: &StsSource) -> Result<String> {
+        match self {
+            Self::File { path, format } => {
+                let contents = tokio::fs::read(path).await.map_err(Error::wrap_io)?;
+                parse_subject_token(&contents, format)
+            }
+            Self::Url {
+                url,
+                headers,
+                format,
+            } => {
+                let client = reqwest::Client::new();
+                let mut req = client.get(url.as_str());
+                for (name, value) in headers {
+                    req = req.header(name, value);
+                }
+                let res = req.send().await.map_err(|e| {
+                    Error::new_with_error("unable to fetch the subject token", e, ErrorKind::Http)
+                })?;
+                if !res.status().is_success() {
+                    return Err(Error::new(
+                        format!("bad request with status: {}", res.status()),
+                        ErrorKind::Http,
+                    ));
+                }
+                let contents = res.bytes().await.map_err(Error::wrap_http)?;
+                parse_subject_token(&contents, format)
+            }
+            Self::Executable {
+                command,
+                timeout,
+                output_file,
+                allow_executables,
+            } => {
+                run_executable(
+                    command,
+                    *timeout,
+                    output_file.as_deref(),
+                    *allow_executables,
+                    sts,
+                )
+                .await
+            }
+        }
+    }
+}
+
+fn parse_subject_token(contents: &[u8], format: &Format) -> Result<String> {
+    let token = match format {
+        Format::Text => String::from_utf8(contents.to_vec()).map_err(Error::wrap_serialization)?,
+        Format::Json {
+            subject_token_field_name,
+        } => {
+            let json: serde_json::Value =
+                serde_json::from_slice(contents).map_err(Error::wrap_serialization)?;
+            json.get(subject_token_field_name)
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    Error::new(
+                        format!("missing subject token field `{subject_token_field_name}`"),
+                        ErrorKind::Validation,
+                    )
+                })?
+        }
+    };
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(Error::new("empty subject token", ErrorKind::Validation));
+    }
+    Ok(token.to_string())
+}
+
+/// The output of an executable sourced credential.
+#[derive(Deserialize)]
+struct ExecutableResponse {
+    version: i32,
+    success: bool,
+    #[serde(default)]
+    token_type: Option<String>,
+    #[serde(default)]
+    id_token: Option<String>,
+    #[serde(default)]
+    saml_response: Option<String>,
+    #[serde(default)]
+    expiration_time: Option<i64>,
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl ExecutableResponse {
+    fn is_expired(&self) -> bool {
+        self.expiration_time
+            .is_some_and(|t| t <= Utc::now().timestamp())
+    }
+
+    fn into_subject_token(self, subject_token_type: &str) -> Result<String> {
+        if self.version != 1 {
+            return Err(Error::new(
+                format!("unsupported executable response version {}", self.version),
+                ErrorKind::Validation,
+            ));
+        }
+        if !self.success {
+            return Err(Error::new(
+                format!(
+                    "the executable failed with code {}: {}",
+                    self.code.as_deref().unwrap_or_default(),
+                    self.message.as_deref().unwrap_or_default()
+                ),
+                ErrorKind::Other,
+            ));
+        }
+        if self.is_expired() {
+            return Err(Error::new(
+                "the executable response is expired",
+                ErrorKind::Validation,
+            ));
+        }
+        if self.token_type.as_deref() != Some(subject_token_type) {
+            return Err(Error::new(
+                format!(
+                    "the executable returned a {:?} token, expected {subject_token_type}",
+                    self.token_type
+                ),
+                ErrorKind::Validation,
+            ));
+        }
+        let token = if subject_token_type == SAML2_TOKEN_TYPE {
+            self.saml_response
+        } else {
+            self.id_token
+        };
+        token.ok_or_else(|| {
+            Error::new(
+                "the executable response does not contain a token",
+                ErrorKind::Validation,
+            )
+        })
+    }
+}
+
+async fn run_executable(
+    command: &str,
+    timeout: std::time::Duration,
+    output_file: Option<&str>,
+    allow_executables: bool,
+    sts: &StsSource,
+) -> Result<String> {
+    if !allow_executables {
+        return Err(Error::new(
+            format!("executable sourced credentials require {ALLOW_EXECUTABLES_ENV}=1"),
+            ErrorKind::Environment,
+        ));
+    }
+    // A cached, unexpired response in the output file avoids running the
+    // executable.
+    if let Some(path) = output_file {
+        if let Ok(contents) = tokio::fs::read(path).await {
+            if let Ok(response) = serde_json::from_slice::<ExecutableResponse>(&contents) {
+                if response.success && !response.is_expired() {
+                    return response.into_subject_token(&sts.subject_token_type);
+                }
+            }
+        }
+    }
+    let mut args = command.split_whitespace();
+    let program = args
+        .next()
+        .ok_or_else(|| Error::new("empty executable command", ErrorKind::Validation))?;
+    let mut cmd = tokio::process::Command::new(program);
+    cmd.args(args)
+        .env("GOOGLE_EXTERNAL_ACCOUNT_AUDIENCE", &sts.audience)
+        .env(
+            "GOOGLE_EXTERNAL_ACCOUNT_TOKEN_TYPE",
+            &sts.subject_token_type,
+        )
+        .env("GOOGLE_EXTERNAL_ACCOUNT_INTERACTIVE", "0")
+        .kill_on_drop(true);
+    if let Some(email) = &sts.impersonated_email {
+        cmd.env("GOOGLE_EXTERNAL_ACCOUNT_IMPERSONATED_EMAIL", email);
+    }
+    if let Some(path) = output_file {
+        cmd.env("GOOGLE_EXTERNAL_ACCOUNT_OUTPUT_FILE", path);
+    }
+    let output = tokio::time::timeout(timeout, cmd.output())
+        .await
+        .map_err(|e| Error::new_with_error("the executable timed out", e, ErrorKind::IO))?
+        .map_err(Error::wrap_io)?;
+    if !output.status.success() {
+        return Err(Error::new(
+            format!("the executable failed with {}", output.status),
+            ErrorKind::Other,
+        ));
+    }
+    let response: ExecutableResponse =
+        serde_json::from_slice(&output.stdout).map_err(Error::wrap_serialization)?;
+    if output_file.is_some() && response.success && response.expiration_time.is_none() {
+        return Err(Error::new(
+            "the executable response must have an expiration_time when output_file is set",
+            ErrorKind::Validation,
+        ));
+    }
+    response.into_subject_token(&sts.subject_token_type)
+}
+
+/// Exchanges subject tokens for access tokens using the Security Token
+/// Service.
+#[derive(Clone)]
+struct StsSource {
+    subject: SubjectTokenSource,
+    audience: String,
+    subject_token_type: String,
+    token_url: String,
+    scopes: Vec<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    workforce_pool_user_project: Option<String>,
+    impersonated_email: Option<String>,
+}
+
+impl StsSource {
+    /// Retrieves an [AccessToken] based on configured source.
+    async fn _fetch_access_token(&self) -> Result<AccessToken> {
+        let subject_token = self.subject.subject_token(self).await?;
+        let scope = self.scopes.join(" ");
+        let options = match (&self.workforce_pool_user_project, &self.client_id) {
+            (Some(project), None) => {
+                Some(serde_json::json!({ "userProject": project }).to_string())
+            }
+            _ => None,
+        };
+        let client = reqwest::Client::new();
+        let mut req = client
+            .post(self.token_url.as_str())
+            .form(&TokenExchangeRequest {
+                grant_type: TOKEN_EXCHANGE_GRANT,
+                audience: &self.audience,
+                scope: &scope,
+                requested_token_type: ACCESS_TOKEN_TYPE,
+                subject_token: &subject_token,
+                subject_token_type: &self.subject_token_type,
+                options: options.as_deref(),
+            });
+        if let Some(client_id) = &self.client_id {
+            req = req.basic_auth(client_id, self.client_secret.as_ref());
+        }
+        let res = req.send().await.map_err(|e| {
+            Error::new_with_error(
+                "unable to make request to the token exchange endpoint",
+                e,
+                ErrorKind::Http,
+            )
+        })?;
+        if !res.status().is_success() {
+            let status = res.status();
+            let body = res.text().await.unwrap_or_default();
+            return Err(Error::new(
+                format!("bad request with status: {status}, body: {body}"),
+                ErrorKind::Http,
+            ));
+        }
+        let token_response: TokenExchangeResponse =
+            res.json().await.map_err(Error::wrap_serialization)?;
+        Ok(AccessToken {
+            value: token_response.access_token,
+            expires: token_response
+                .expires_in
+                .map(|s| Utc::now() + Duration::seconds(s)),
+        })
+    }
+}
+
+#[async_trait]
+impl Source for StsSource {
+    async fn token(&self) -> Result<AccessToken> {
+        self._fetch_access_token().await
+    }
+}
+
+/// The request body of an [RFC 8693] token exchange.
+///
+/// [RFC 8693]: https://www.rfc-editor.org/rfc/rfc8693
+#[derive(Serialize)]
+struct TokenExchangeRequest<'a> {
+    grant_type: &'a str,
+    audience: &'a str,
+    scope: &'a str,
+    requested_token_type: &'a str,
+    subject_token: &'a str,
+    subject_token_type: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<&'a str>,
+}
+
+/// The response of an [RFC 8693] token exchange.
+///
+/// [RFC 8693]: https://www.rfc-editor.org/rfc/rfc8693
+#[derive(Deserialize)]
+struct TokenExchangeResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::{Form, State};
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::Json;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    const JWT_TOKEN_TYPE: &str = "urn:ietf:params:oauth:token-type:jwt";
+    const AUDIENCE: &str =
+        "//iam.googleapis.com/projects/123/locations/global/workloadIdentityPools/pool/providers/provider";
+
+    #[derive(Clone, Default)]
+    struct Requests {
+        exchanges: Arc<Mutex<Vec<HashMap<String, String>>>>,
+        impersonations: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
+    }
+
+    /// Starts a fake STS and IAM Credentials service, returning its endpoint.
+    async fn start_fake_services() -> (String, Requests) {
+        async fn subject_token(headers: HeaderMap) -> (StatusCode, String) {
+            match headers.get("x-test-header").and_then(|v| v.to_str().ok()) {
+                Some("test-value") => (
+                    StatusCode::OK,
+                    r#"{"token": "url-subject-token"}"#.to_string(),
+                ),
+                _ => (StatusCode::UNAUTHORIZED, String::new()),
+            }
+        }
+        async fn exchange(
+            State(requests): State<Requests>,
+            Form(form): Form<HashMap<String, String>>,
+        ) -> Json<serde_json::Value> {
+            let token = format!("sts-token-for-{}", form["subject_token"]);
+            requests.exchanges.lock().unwrap().push(form);
+            Json(json!({
+                "access_token": token,
+                "issued_token_type": ACCESS_TOKEN_TYPE,
+                "token_type": "Bearer",
+                "expires_in": 3600,
+            }))
+        }
+        async fn impersonate(
+            State(requests): State<Requests>,
+            headers: HeaderMap,
+            Json(body): Json<serde_json::Value>,
+        ) -> Json<serde_json::Value> {
+            let auth = headers["authorization"].to_str().unwrap().to_string();
+            requests.impersonations.lock().unwrap().push((auth, body));
+            Json(json!({
+                "accessToken": "impersonated-token",
+                "expireTime": "2030-01-02T03:04:05Z",
+            }))
+        }
+
+        let requests = Requests::default();
+        let app = axum::Router::new()
+            .route("/subject", axum::routing::get(subject_token))
+            .route("/v1/token", axum::routing::post(exchange))
+            .route("/v1/{*name}", axum::routing::post(impersonate))
+            .with_state(requests.clone());
+        let endpoint = format!("http://{}", crate::test_utils::serve(app).await);
+        (endpoint, requests)
+    }
+
+    /// A file in the temporary directory, removed when dropped.
+    struct TempFile(String);
+
+    impl TempFile {
+        fn new(name: &str, contents: &str) -> Self {
+            let path = std::env::temp_dir()
+                .join(format!("external-account-{}-{name}", std::process::id()));
+            std::fs::write(&path, contents).unwrap();
+            Self(path.to_string_lossy().to_string())
+        }
+
+        fn path(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    fn config(endpoint: &str) -> ExternalAccountSourceConfig {
+        ExternalAccountSourceConfig {
+            scopes: vec!["scope1".to_string(), "scope2".to_string()],
+            token_url: Some(format!("{endpoint}/v1/token")),
+        }
+    }
+
+    fn source(credential_source: serde_json::Value, endpoint: &str) -> ExternalAccountSource {
+        source_with_executables(credential_source, endpoint, false)
+    }
+
+    fn source_with_executables(
+        credential_source: serde_json::Value,
+        endpoint: &str,
+        allow_executables: bool,
+    ) -> ExternalAccountSource {
+        let contents = json!({
+            "type": "external_account",
+            "audience": AUDIENCE,
+            "subject_token_type": JWT_TOKEN_TYPE,
+            "token_url": "https://sts.googleapis.com/v1/token",
+            "credential_source": credential_source,
+        });
+        ExternalAccountSource::from_contents(
+            contents.to_string().as_bytes(),
+            config(endpoint),
+            allow_executables,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn file_sourced_text() -> Result<()> {
+        let (endpoint, requests) = start_fake_services().await;
+        let file = TempFile::new("text", "file-subject-token\n");
+        let token = source(json!({"file": file.path()}), &endpoint)
+            .token()
+            .await?;
+        assert_eq!(token.value, "sts-token-for-file-subject-token");
+        assert!(token.is_validish());
+
+        let exchanges = requests.exchanges.lock().unwrap();
+        assert_eq!(exchanges.len(), 1);
+        let form = &exchanges[0];
+        assert_eq!(form["grant_type"], TOKEN_EXCHANGE_GRANT);
+        assert_eq!(form["audience"], AUDIENCE);
+        assert_eq!(form["scope"], "scope1 scope2");
+        assert_eq!(form["requested_token_type"], ACCESS_TOKEN_TYPE);
+        assert_eq!(form["subject_token_type"], JWT_TOKEN_TYPE);
+        assert!(!form.contains_key("options"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_sourced_json() -> Result<()> {
+        let (endpoint, _) = start_fake_services().await;
+        let file = TempFile::new("json", r#"{"access_token": "json-subject-token"}"#);
+        let credential_source = json!({
+            "file": file.path(),
+            "format": {"type": "json", "subject_token_field_name": "access_token"},
+        });
+        let token = source(credential_source, &endpoint).token().await?;
+        assert_eq!(token.value, "sts-token-for-json-subject-token");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn file_sourced_missing_field() {
+        let (endpoint, _) = start_fake_services().await;
+        let file = TempFile::new("missing", r#"{"other": "value"}"#);
+        let credential_source = json!({
+            "file": file.path(),
+            "format": {"type": "json", "subject_token_field_name": "access_token"},
+        });
+        let err = source(credential_source, &endpoint)
+            .token()
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+    }
+
+    #[tokio::test]
+    async fn url_sourced() -> Result<()> {
+        let (endpoint, _) = start_fake_services().await;
+        let credential_source = json!({
+            "url": format!("{endpoint}/subject"),
+            "headers": {"x-test-header": "test-value"},
+            "format": {"type": "json", "subject_token_field_name": "token"},
+        });
+        let token = source(credential_source, &endpoint).token().await?;
+        assert_eq!(token.value, "sts-token-for-url-subject-token");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn url_sourced_error() {
+        let (endpoint, _) = start_fake_services().await;
+        let credential_source = json!({"url": format!("{endpoint}/subject")});
+        let err = source(credential_source, &endpoint)
+            .token()
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Http);
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn executable_sourced() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (endpoint, _) = start_fake_services().await;
+        let response = json!({
+            "version": 1,
+            "success": true,
+            "token_type": JWT_TOKEN_TYPE,
+            "id_token": "executable-subject-token",
+            "expiration_time": Utc::now().timestamp() + 3600,
+        });
+        let script = TempFile::new(
+            "executable.sh",
+            &format!(
+                "#!/bin/sh\ntest \"$GOOGLE_EXTERNAL_ACCOUNT_AUDIENCE\" = \"{AUDIENCE}\" || exit 1\necho '{response}'\n"
+            ),
+        );
+        std::fs::set_permissions(script.path(), std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let credential_source =
+            json!({"executable": {"command": script.path(), "timeout_millis": 5000}});
+        let token = source_with_executables(credential_source, &endpoint, true)
+            .token()
+            .await?;
+        assert_eq!(token.value, "sts-token-for-executable-subject-token");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn executable_sourced_disabled() {
+        let (endpoint, requests) = start_fake_services().await;
+        let credential_source = json!({"executable": {"command": "/bin/false"}});
+        let err = source(credential_source, &endpoint)
+            .token()
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Environment);
+        assert!(err.to_string().contains(ALLOW_EXECUTABLES_ENV), "{err}");
+        assert!(requests.exchanges.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn executable_response() {
+        let response =
+            |v: serde_json::Value| serde_json::from_value::<ExecutableResponse>(v).unwrap();
+
+        let saml = response(json!({
+            "version": 1, "success": true, "token_type": SAML2_TOKEN_TYPE, "saml_response": "saml",
+        }));
+        assert_eq!(saml.into_subject_token(SAML2_TOKEN_TYPE).unwrap(), "saml");
+
+        let failed = response(json!({
+            "version": 1, "success": false, "code": "401", "message": "denied",
+        }));
+        let err = failed.into_subject_token(JWT_TOKEN_TYPE).err().unwrap();
+        assert!(err.to_string().contains("denied"), "{err}");
+
+        let expired = response(json!({
+            "version": 1, "success": true, "token_type": JWT_TOKEN_TYPE, "id_token": "t",
+            "expiration_time": Utc::now().timestamp() - 10,
+        }));
+        let err = expired.into_subject_token(JWT_TOKEN_TYPE).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+
+        let mismatch = response(json!({
+            "version": 1, "success": true, "token_type": SAML2_TOKEN_TYPE, "saml_response": "saml",
+        }));
+        let err = mismatch.into_subject_token(JWT_TOKEN_TYPE).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+
+        let version = response(json!({"version": 2, "success": true}));
+        let err = version.into_subject_token(JWT_TOKEN_TYPE).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+    }
+
+    #[tokio::test]
+    async fn impersonation() -> Result<()> {
+        let (endpoint, requests) = start_fake_services().await;
+        let file = TempFile::new("impersonation", "file-subject-token");
+        let contents = json!({
+            "type": "external_account",
+            "audience": AUDIENCE,
+            "subject_token_type": JWT_TOKEN_TYPE,
+            "credential_source": {"file": file.path()},
+            "service_account_impersonation_url": format!("{endpoint}/v1/projects/-/serviceAccounts/sa@example.com:generateAccessToken"),
+            "service_account_impersonation": {"token_lifetime_seconds": 600},
+        });
+        let source = ExternalAccountSource::from_file_contents(
+            contents.to_string().as_bytes(),
+            config(&endpoint),
+        )?;
+        let token = source.token().await?;
+        assert_eq!(token.value, "impersonated-token");
+
+        let exchanges = requests.exchanges.lock().unwrap();
+        assert_eq!(exchanges[0]["scope"], CLOUD_PLATFORM_SCOPE);
+        let impersonations = requests.impersonations.lock().unwrap();
+        let (auth, body) = &impersonations[0];
+        assert_eq!(auth, "Bearer sts-token-for-file-subject-token");
+        assert_eq!(
+            body,
+            &json!({"scope": ["scope1", "scope2"], "lifetime": "600s"})
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn workforce_pool_user_project() -> Result<()> {
+        let (endpoint, requests) = start_fake_services().await;
+        let file = TempFile::new("workforce", "file-subject-token");
+        let contents = json!({
+            "type": "external_account",
+            "audience": "//iam.googleapis.com/locations/global/workforcePools/pool/providers/provider",
+            "subject_token_type": JWT_TOKEN_TYPE,
+            "credential_source": {"file": file.path()},
+            "workforce_pool_user_project": "test-project",
+        });
+        let source = ExternalAccountSource::from_file_contents(
+            contents.to_string().as_bytes(),
+            config(&endpoint),
+        )?;
+        source.token().await?;
+        let exchanges = requests.exchanges.lock().unwrap();
+        assert_eq!(exchanges[0]["options"], r#"{"userProject":"test-project"}"#);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_credential_source() {
+        let parse = |credential_source: serde_json::Value| {
+            let contents = json!({
+                "type": "external_account",
+                "audience": AUDIENCE,
+                "subject_token_type": JWT_TOKEN_TYPE,
+                "credential_source": credential_source,
+            });
+            ExternalAccountSource::from_file_contents(
+                contents.to_string().as_bytes(),
+                config("http://localhost"),
+            )
+            .err()
+            .unwrap()
+            .kind()
+        };
+        assert_eq!(parse(json!({})), ErrorKind::Validation);
+        assert_eq!(
+            parse(json!({"file": "a", "url": "b"})),
+            ErrorKind::Validation
+        );
+        assert_eq!(
+            parse(json!({"environment_id": "aws1"})),
+            ErrorKind::Validation
+        );
+        assert_eq!(
+            parse(json!({"executable": {"command": "a", "timeout_millis": 1000}})),
+            ErrorKind::Validation
+        );
+    }
+
+    #[test]
+    fn impersonated_emails() {
+        assert_eq!(
+            impersonated_email("https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/sa@example.com:generateAccessToken"),
+            Some("sa@example.com".to_string())
+        );
+        assert_eq!(impersonated_email("https://example.com"), None);
+    }
+}
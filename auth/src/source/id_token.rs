@@ -0,0 +1,514 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sources of OIDC ID tokens, used with [IdTokenCredential].
+//!
+//! These sources return the ID tokens as the value of an [AccessToken], which
+//! expires with the `exp` claim of the ID token.
+//!
+//! [IdTokenCredential]: crate::IdTokenCredential
+
+use super::{
+    impersonation_base_source, service_account_resource, sign_jwt, ImpersonatedCredentialFile,
+    ServiceAccountKeyFile, ServiceAccountKeySource, ServiceAccountTokenRequest, Source,
+    DEFAULT_HEADER, DEFAULT_OAUTH_GRANT, IAM_CREDENTIALS_ENDPOINT,
+};
+use crate::oauth2::JwsClaims;
+use crate::{metadata, AccessToken, Error, ErrorKind, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A [Source] of ID tokens derived from a Service Account Key.
+///
+/// The ID tokens are minted by the OAuth 2.0 token endpoint in exchange for a
+/// JWT assertion with a `target_audience` claim.
+#[derive(Clone)]
+pub struct ServiceAccountIdTokenSource {
+    key: ServiceAccountKeySource,
+    audience: String,
+}
+
+impl ServiceAccountIdTokenSource {
+    /// Create a [ServiceAccountIdTokenSource] from a file path.
+    pub async fn from_file(path: impl AsRef<Path>, audience: impl Into<String>) -> Result<Self> {
+        let contents = tokio::fs::read(path).await.map_err(Error::wrap_io)?;
+        Self::from_file_contents(&contents, audience)
+    }
+
+    /// Create a [ServiceAccountIdTokenSource] from bytes.
+    pub fn from_file_contents(contents: &[u8], audience: impl Into<String>) -> Result<Self> {
+        let audience = validate_audience(audience.into())?;
+        let file: ServiceAccountKeyFile =
+            serde_json::from_slice(contents).map_err(Error::wrap_serialization)?;
+        let key = ServiceAccountKeySource {
+            file,
+            scopes: Vec::new(),
+            self_signed_jwt: false,
+            audience: None,
+        };
+        Ok(Self { key, audience })
+    }
+
+    /// Retrieves an ID token based on configured source.
+    async fn _fetch_id_token(&self) -> Result<AccessToken> {
+        let mut claims = JwsClaims {
+            iss: self.key.file.client_email.as_str(),
+            scope: None,
+            aud: Some(self.key.file.token_uri.as_str()),
+            exp: None,
+            iat: None,
+            sub: None,
+            typ: None,
+            target_audience: Some(self.audience.as_str()),
+        };
+        let assertion = sign_jwt(&self.key.signer()?, &DEFAULT_HEADER, &mut claims)?;
+        let client = reqwest::Client::new();
+        let res = client
+            .post(self.key.file.token_uri.as_str())
+            .form(&ServiceAccountTokenRequest {
+                grant_type: DEFAULT_OAUTH_GRANT.into(),
+                assertion,
+            })
+            .send()
+            .await
+            .map_err(|e| {
+                Error::new_with_error(
+                    "unable to make request to oauth endpoint",
+                    e,
+                    ErrorKind::Http,
+                )
+            })?;
+        if !res.status().is_success() {
+            return Err(Error::new(
+                format!("bad request with status: {}", res.status()),
+                ErrorKind::Http,
+            ));
+        }
+        let response: IdTokenResponse = res.json().await.map_err(Error::wrap_serialization)?;
+        id_token(response.id_token)
+    }
+}
+
+#[async_trait]
+impl Source for ServiceAccountIdTokenSource {
+    async fn token(&self) -> Result<AccessToken> {
+        self._fetch_id_token().await
+    }
+}
+
+/// The response of a Service Account Key ID token request.
+#[derive(Deserialize)]
+struct IdTokenResponse {
+    id_token: String,
+}
+
+/// A [Source] of ID tokens derived from the Google Cloud metadata service.
+#[derive(Clone)]
+pub struct ComputeIdTokenSource {
+    audience: String,
+    client: metadata::MetadataClient,
+}
+
+impl ComputeIdTokenSource {
+    /// Creates a [ComputeIdTokenSource] for the provided audience.
+    pub fn new(audience: impl Into<String>) -> Result<Self> {
+        let audience = validate_audience(audience.into())?;
+        Self::with_client(audience, metadata::MetadataClient::new()?)
+    }
+
+    /// Creates a [ComputeIdTokenSource] that fetches the ID tokens with the
+    /// provided [MetadataClient][metadata::MetadataClient].
+    pub fn with_client(
+        audience: impl Into<String>,
+        client: metadata::MetadataClient,
+    ) -> Result<Self> {
+        let audience = validate_audience(audience.into())?;
+        Ok(Self { audience, client })
+    }
+}
+
+#[async_trait]
+impl Source for ComputeIdTokenSource {
+    async fn token(&self) -> Result<AccessToken> {
+        let token = self.client.id_token(None, &self.audience).await?;
+        id_token(token)
+    }
+}
+
+/// Configuration for building an [ImpersonatedIdTokenSource].
+#[derive(Clone, Default)]
+pub struct ImpersonatedIdTokenSourceConfig {
+    /// The email of the service account to impersonate.
+    pub target_principal: String,
+    /// The chain of service accounts used to reach the target principal.
+    pub delegates: Vec<String>,
+    /// The audience of the minted ID tokens.
+    pub audience: String,
+    /// If true, the ID tokens include the `email` and `email_verified` claims.
+    pub include_email: bool,
+    /// Overrides the IAM Credentials endpoint, mostly useful in tests.
+    pub endpoint: Option<String>,
+}
+
+/// A [Source] of ID tokens for an impersonated service account.
+///
+/// The ID tokens are minted by the IAM Credentials [generateIdToken] method,
+/// authenticated with the tokens of another [Source].
+///
+/// [generateIdToken]: https://cloud.google.com/iam/docs/reference/credentials/rest/v1/projects.serviceAccounts/generateIdToken
+#[derive(Clone)]
+pub struct ImpersonatedIdTokenSource {
+    source: Box<dyn Source + Send + Sync>,
+    url: String,
+    delegates: Vec<String>,
+    audience: String,
+    include_email: bool,
+}
+
+impl ImpersonatedIdTokenSource {
+    /// Creates an [ImpersonatedIdTokenSource] using `source` to authenticate
+    /// the requests to the IAM Credentials service.
+    pub fn new(
+        source: Box<dyn Source + Send + Sync>,
+        config: ImpersonatedIdTokenSourceConfig,
+    ) -> Result<Self> {
+        if config.target_principal.is_empty() {
+            return Err(Error::new(
+                "target principal must be provided",
+                ErrorKind::Validation,
+            ));
+        }
+        let audience = validate_audience(config.audience)?;
+        let endpoint = config
+            .endpoint
+            .as_deref()
+            .unwrap_or(IAM_CREDENTIALS_ENDPOINT)
+            .trim_end_matches('/');
+        Ok(Self {
+            source,
+            url: format!(
+                "{endpoint}/v1/{}:generateIdToken",
+                service_account_resource(&config.target_principal)
+            ),
+            delegates: config.delegates,
+            audience,
+            include_email: config.include_email,
+        })
+    }
+
+    /// Create an [ImpersonatedIdTokenSource] from the bytes of an impersonated
+    /// service account credential file.
+    pub fn from_file_contents(contents: &[u8], audience: impl Into<String>) -> Result<Self> {
+        let audience = validate_audience(audience.into())?;
+        let file: ImpersonatedCredentialFile =
+            serde_json::from_slice(contents).map_err(Error::wrap_serialization)?;
+        let url = file
+            .service_account_impersonation_url
+            .strip_suffix(":generateAccessToken")
+            .map(|prefix| format!("{prefix}:generateIdToken"))
+            .ok_or_else(|| {
+                Error::new(
+                    format!(
+                        "unexpected service account impersonation URL: {}",
+                        file.service_account_impersonation_url
+                    ),
+                    ErrorKind::Validation,
+                )
+            })?;
+        Ok(Self {
+            source: impersonation_base_source(&file.source_credentials)?,
+            url,
+            delegates: file.delegates,
+            audience,
+            include_email: true,
+        })
+    }
+
+    /// Retrieves an ID token based on configured source.
+    async fn _fetch_id_token(&self) -> Result<AccessToken> {
+        let source_token = self.source.token().await?;
+        let client = reqwest::Client::new();
+        let res = client
+            .post(self.url.as_str())
+            .bearer_auth(source_token.value)
+            .json(&GenerateIdTokenRequest {
+                audience: &self.audience,
+                delegates: self
+                    .delegates
+                    .iter()
+                    .map(|d| service_account_resource(d))
+                    .collect(),
+                include_email: self.include_email,
+            })
+            .send()
+            .await
+            .map_err(|e| {
+                Error::new_with_error(
+                    "unable to make request to IAM credentials endpoint",
+                    e,
+                    ErrorKind::Http,
+                )
+            })?;
+        if !res.status().is_success() {
+            return Err(Error::new(
+                format!("bad request with status: {}", res.status()),
+                ErrorKind::Http,
+            ));
+        }
+        let response: GenerateIdTokenResponse =
+            res.json().await.map_err(Error::wrap_serialization)?;
+        id_token(response.token)
+    }
+}
+
+#[async_trait]
+impl Source for ImpersonatedIdTokenSource {
+    async fn token(&self) -> Result<AccessToken> {
+        self._fetch_id_token().await
+    }
+}
+
+/// The request body of an IAM Credentials generateIdToken call.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateIdTokenRequest<'a> {
+    audience: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    delegates: Vec<String>,
+    include_email: bool,
+}
+
+/// The response of an IAM Credentials generateIdToken call.
+#[derive(Deserialize)]
+struct GenerateIdTokenResponse {
+    token: String,
+}
+
+fn validate_audience(audience: String) -> Result<String> {
+    if audience.is_empty() {
+        return Err(Error::new(
+            "audience must be provided",
+            ErrorKind::Validation,
+        ));
+    }
+    Ok(audience)
+}
+
+/// Wraps an ID token in an [AccessToken] that expires with its `exp` claim.
+fn id_token(token: String) -> Result<AccessToken> {
+    let expires = id_token_expiration(&token)?;
+    Ok(AccessToken {
+        value: token,
+        expires: Some(expires),
+    })
+}
+
+/// Decodes the `exp` claim of an ID token. The signature is not verified, the
+/// token was received directly from a trusted service.
+fn id_token_expiration(token: &str) -> Result<DateTime<Utc>> {
+    use base64::prelude::{Engine as _, BASE64_URL_SAFE_NO_PAD};
+
+    #[derive(Deserialize)]
+    struct Claims {
+        exp: i64,
+    }
+
+    let invalid = || Error::new("invalid ID token", ErrorKind::Validation);
+    let payload = token.split('.').nth(1).ok_or_else(invalid)?;
+    let payload = BASE64_URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .map_err(|e| Error::new_with_error("invalid ID token", e, ErrorKind::Validation))?;
+    let claims: Claims = serde_json::from_slice(&payload).map_err(Error::wrap_serialization)?;
+    DateTime::from_timestamp(claims.exp, 0).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::serve;
+    use axum::extract::{Form, Query};
+    use axum::http::HeaderMap;
+    use axum::Json;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    /// Creates an unsigned JWT with the provided claims.
+    fn fake_id_token(claims: serde_json::Value) -> String {
+        use base64::prelude::{Engine as _, BASE64_URL_SAFE_NO_PAD};
+        let header = BASE64_URL_SAFE_NO_PAD.encode(r#"{"alg":"RS256","typ":"JWT"}"#);
+        let claims = BASE64_URL_SAFE_NO_PAD.encode(claims.to_string());
+        format!("{header}.{claims}.signature")
+    }
+
+    fn decode_claims(jwt: &str) -> serde_json::Value {
+        use base64::prelude::{Engine as _, BASE64_URL_SAFE_NO_PAD};
+        let payload = jwt.split('.').nth(1).unwrap();
+        serde_json::from_slice(&BASE64_URL_SAFE_NO_PAD.decode(payload).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn expiration() -> Result<()> {
+        let token = fake_id_token(json!({"aud": "a", "exp": 1_900_000_000}));
+        assert_eq!(id_token_expiration(&token)?.timestamp(), 1_900_000_000);
+
+        let token = fake_id_token(json!({"aud": "a"}));
+        assert_eq!(
+            id_token_expiration(&token).err().unwrap().kind(),
+            ErrorKind::Serialization
+        );
+        assert_eq!(
+            id_token_expiration("not-a-jwt").err().unwrap().kind(),
+            ErrorKind::Validation
+        );
+        assert_eq!(
+            id_token_expiration("a.!!!.b").err().unwrap().kind(),
+            ErrorKind::Validation
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn service_account() -> Result<()> {
+        let expires = Utc::now().timestamp() + 3600;
+        let handler = move |Form(form): Form<HashMap<String, String>>| async move {
+            assert_eq!(form["grant_type"], DEFAULT_OAUTH_GRANT);
+            let claims = decode_claims(&form["assertion"]);
+            assert_eq!(claims["target_audience"], "https://example.run.app");
+            assert!(claims.get("scope").is_none(), "{claims}");
+            Json(json!({
+                "id_token": fake_id_token(json!({"aud": claims["target_audience"], "exp": expires})),
+            }))
+        };
+        let app = axum::Router::new().route("/token", axum::routing::post(handler));
+        let addr = serve(app).await;
+
+        let mut key: serde_json::Value =
+            serde_json::from_str(&super::super::tests::service_account_key()).unwrap();
+        key["token_uri"] = json!(format!("http://{addr}/token"));
+        let source = ServiceAccountIdTokenSource::from_file_contents(
+            key.to_string().as_bytes(),
+            "https://example.run.app",
+        )?;
+        let token = source.token().await?;
+        assert_eq!(
+            decode_claims(&token.value)["aud"],
+            "https://example.run.app"
+        );
+        assert_eq!(token.expires.map(|e| e.timestamp()), Some(expires));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn compute() -> Result<()> {
+        let expires = Utc::now().timestamp() + 3600;
+        let handler = move |Query(query): Query<HashMap<String, String>>, headers: HeaderMap| async move {
+            assert_eq!(headers["metadata-flavor"], "Google");
+            assert_eq!(query["format"], "full");
+            fake_id_token(json!({"aud": query["audience"], "exp": expires}))
+        };
+        let app = axum::Router::new().route(
+            "/computeMetadata/v1/instance/service-accounts/default/identity",
+            axum::routing::get(handler),
+        );
+        let client = metadata::MetadataClient::builder()
+            .host(serve(app).await)
+            .build()?;
+
+        let token = ComputeIdTokenSource::with_client("https://example.run.app", client)?
+            .token()
+            .await?;
+        assert_eq!(
+            decode_claims(&token.value)["aud"],
+            "https://example.run.app"
+        );
+        assert_eq!(token.expires.map(|e| e.timestamp()), Some(expires));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn impersonated() -> Result<()> {
+        let expires = Utc::now().timestamp() + 3600;
+        let handler = move |headers: HeaderMap, Json(body): Json<serde_json::Value>| async move {
+            assert_eq!(headers["authorization"], "Bearer token-0");
+            assert_eq!(
+                body,
+                json!({
+                    "audience": "https://example.run.app",
+                    "delegates": ["projects/-/serviceAccounts/delegate@example.com"],
+                    "includeEmail": true,
+                })
+            );
+            Json(json!({"token": fake_id_token(json!({"aud": body["audience"], "exp": expires}))}))
+        };
+        let app = axum::Router::new().route(
+            "/v1/projects/-/serviceAccounts/{name}",
+            axum::routing::post(handler),
+        );
+        let addr = serve(app).await;
+
+        let source = ImpersonatedIdTokenSource::new(
+            Box::new(super::super::StaticTokenSource::new("token-0", None)),
+            ImpersonatedIdTokenSourceConfig {
+                target_principal: "test-only@example.com".to_string(),
+                delegates: vec!["delegate@example.com".to_string()],
+                audience: "https://example.run.app".to_string(),
+                include_email: true,
+                endpoint: Some(format!("http://{addr}")),
+            },
+        )?;
+        assert_eq!(
+            source.url,
+            format!(
+                "http://{addr}/v1/projects/-/serviceAccounts/test-only@example.com:generateIdToken"
+            )
+        );
+        let token = source.token().await?;
+        assert_eq!(token.expires.map(|e| e.timestamp()), Some(expires));
+        Ok(())
+    }
+
+    #[test]
+    fn impersonated_from_file() -> Result<()> {
+        let contents = json!({
+            "type": "impersonated_service_account",
+            "service_account_impersonation_url": "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/test-only@example.com:generateAccessToken",
+            "source_credentials": {"type": "authorized_user", "client_id": "test-only-id.apps.googleusercontent.com", "client_secret": "test-only-client-secret", "refresh_token": "test-only-refresh-token"},
+        });
+        let source = ImpersonatedIdTokenSource::from_file_contents(
+            contents.to_string().as_bytes(),
+            "https://example.run.app",
+        )?;
+        assert_eq!(source.url, "https://iamcredentials.googleapis.com/v1/projects/-/serviceAccounts/test-only@example.com:generateIdToken");
+        assert!(source.include_email);
+        Ok(())
+    }
+
+    #[test]
+    fn requires_audience() {
+        let err = ComputeIdTokenSource::new("").err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+        let err = ImpersonatedIdTokenSource::new(
+            Box::new(super::super::NoOpSource {}),
+            ImpersonatedIdTokenSourceConfig {
+                target_principal: "test-only@example.com".to_string(),
+                ..Default::default()
+            },
+        )
+        .err()
+        .unwrap();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+    }
+}
@@ -213,6 +213,9 @@ type Message struct {
 	// IsPageableResponse indicated that this Message is returned by a standard
 	// List RPC and conforms to [AIP-4233](https://google.aip.dev/client-libraries/4233).
 	IsPageableResponse bool
+	// PageableItem is the repeated field holding the items in a pageable
+	// response. It is only set if IsPageableResponse is true.
+	PageableItem *Field
 }
 
 // Enum defines a message used in request/response handling.
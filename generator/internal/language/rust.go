@@ -193,10 +193,35 @@ func (c *RustCodec) LoadWellKnownTypes(s *api.APIState) {
 			Name:    "Timestamp",
 			Package: "google.protobuf",
 		},
+		{
+			ID:      ".google.protobuf.Struct",
+			Name:    "Struct",
+			Package: "google.protobuf",
+		},
+		{
+			ID:      ".google.protobuf.Value",
+			Name:    "Value",
+			Package: "google.protobuf",
+		},
+		{
+			ID:      ".google.protobuf.ListValue",
+			Name:    "ListValue",
+			Package: "google.protobuf",
+		},
 	}
 	for _, message := range wellKnown {
 		s.MessageByID[message.ID] = message
 	}
+	wellKnownEnums := []*api.Enum{
+		{
+			ID:      ".google.protobuf.NullValue",
+			Name:    "NullValue",
+			Package: "google.protobuf",
+		},
+	}
+	for _, enum := range wellKnownEnums {
+		s.EnumByID[enum.ID] = enum
+	}
 }
 
 func ScalarFieldType(f *api.Field) string {
@@ -467,7 +492,7 @@ func (c *RustCodec) messageScopeName(m *api.Message, childPackageName string) st
 }
 
 func (c *RustCodec) enumScopeName(e *api.Enum) string {
-	return c.messageScopeName(e.Parent, "")
+	return c.messageScopeName(e.Parent, e.Package)
 }
 
 func (c *RustCodec) FQMessageName(m *api.Message, _ *api.APIState) string {
@@ -479,7 +504,7 @@ func (c *RustCodec) EnumName(e *api.Enum, state *api.APIState) string {
 }
 
 func (c *RustCodec) FQEnumName(e *api.Enum, _ *api.APIState) string {
-	return c.messageScopeName(e.Parent, "") + "::" + c.ToPascal(e.Name)
+	return c.messageScopeName(e.Parent, e.Package) + "::" + c.ToPascal(e.Name)
 }
 
 func (c *RustCodec) EnumValueName(e *api.EnumValue, _ *api.APIState) string {
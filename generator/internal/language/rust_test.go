@@ -214,11 +214,26 @@ func TestWellKnownTypesExist(t *testing.T) {
 	api := newTestAPI([]*api.Message{}, []*api.Enum{}, []*api.Service{})
 	c := &RustCodec{}
 	c.LoadWellKnownTypes(api.State)
-	for _, name := range []string{"Any", "Duration", "Empty", "FieldMask", "Timestamp"} {
+	for _, name := range []string{"Any", "Duration", "Empty", "FieldMask", "Timestamp", "Struct", "Value", "ListValue"} {
 		if _, ok := api.State.MessageByID[fmt.Sprintf(".google.protobuf.%s", name)]; !ok {
 			t.Errorf("cannot find well-known message %s in API", name)
 		}
 	}
+	if _, ok := api.State.EnumByID[".google.protobuf.NullValue"]; !ok {
+		t.Errorf("cannot find well-known enum NullValue in API")
+	}
+}
+
+func TestRust_WellKnownEnumAsField(t *testing.T) {
+	api := newTestAPI([]*api.Message{}, []*api.Enum{}, []*api.Service{})
+	c := createRustCodec()
+	c.LoadWellKnownTypes(api.State)
+
+	want := "gax_wkt::NullValue"
+	got := c.FQEnumName(api.State.EnumByID[".google.protobuf.NullValue"], api.State)
+	if want != got {
+		t.Errorf("mismatched well-known enum name, want=%s, got=%s", want, got)
+	}
 }
 
 func TestRust_WellKnownTypesAsMethod(t *testing.T) {
@@ -618,6 +618,14 @@ func TestOpenAPI_MakeAPI(t *testing.T) {
 			},
 		},
 		IsPageableResponse: true,
+		PageableItem: &api.Field{
+			Name:          "locations",
+			JSONName:      "locations",
+			Documentation: "A list of locations that matches the specified filter in the request.",
+			Typez:         api.MESSAGE_TYPE,
+			TypezID:       "..Location",
+			Repeated:      true,
+		},
 	})
 
 	// This is a synthetic message, the OpenAPI spec does not contain requests
@@ -906,6 +914,13 @@ func TestOpenAPI_Pagination(t *testing.T) {
 		Name:               "ListFoosResponse",
 		ID:                 "..ListFoosResponse",
 		IsPageableResponse: true,
+		PageableItem: &api.Field{
+			Name:     "secrets",
+			Typez:    11,
+			TypezID:  "..Foo",
+			JSONName: "secrets",
+			Repeated: true,
+		},
 		Fields: []*api.Field{
 			{
 				Name:     "nextPageToken",
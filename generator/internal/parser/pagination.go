@@ -56,22 +56,23 @@ func updateMethodPagination(a *api.API) {
 			continue
 		}
 		var hasNextPageToken bool
-		var hasRepeatedItem bool
+		var pageableItem *api.Field
 		for _, f := range respMsg.Fields {
 			if f.JSONName == nextPageToken && f.Typez == api.STRING_TYPE {
 				hasNextPageToken = true
 			}
-			if f.Repeated && f.Typez == api.MESSAGE_TYPE {
-				hasRepeatedItem = true
+			if pageableItem == nil && f.Repeated && f.Typez == api.MESSAGE_TYPE {
+				pageableItem = f
 			}
-			if hasNextPageToken && hasRepeatedItem {
+			if hasNextPageToken && pageableItem != nil {
 				break
 			}
 		}
-		if !(hasNextPageToken && hasRepeatedItem) {
+		if !hasNextPageToken || pageableItem == nil {
 			continue
 		}
 		m.IsPageable = true
 		respMsg.IsPageableResponse = true
+		respMsg.PageableItem = pageableItem
 	}
 }
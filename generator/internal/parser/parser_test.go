@@ -24,8 +24,9 @@ import (
 
 func checkMessage(t *testing.T, got api.Message, want api.Message) {
 	t.Helper()
-	// Checking Parent, Messages, Fields, and OneOfs requires special handling.
-	if diff := cmp.Diff(want, got, cmpopts.IgnoreFields(api.Message{}, "Fields", "OneOfs", "Parent", "Messages")); diff != "" {
+	// Checking Parent, Messages, Fields, OneOfs, and PageableItem requires
+	// special handling.
+	if diff := cmp.Diff(want, got, cmpopts.IgnoreFields(api.Message{}, "Fields", "OneOfs", "Parent", "Messages", "PageableItem")); diff != "" {
 		t.Errorf("message attributes mismatch (-want +got):\n%s", diff)
 	}
 	less := func(a, b *api.Field) bool { return a.Name < b.Name }
@@ -36,6 +37,9 @@ func checkMessage(t *testing.T, got api.Message, want api.Message) {
 	if diff := cmp.Diff(want.OneOfs, got.OneOfs, cmpopts.SortSlices(less), cmpopts.IgnoreFields(api.OneOf{}, "Parent")); diff != "" {
 		t.Errorf("oneofs mismatch (-want, +got):\n%s", diff)
 	}
+	if diff := cmp.Diff(want.PageableItem, got.PageableItem); diff != "" {
+		t.Errorf("pageable item mismatch (-want, +got):\n%s", diff)
+	}
 }
 
 func checkEnum(t *testing.T, got api.Enum, want api.Enum) {
@@ -1032,6 +1032,14 @@ func TestProtobuf_Pagination(t *testing.T) {
 		ID:                 ".test.ListFooResponse",
 		Package:            "test",
 		IsPageableResponse: true,
+		PageableItem: &api.Field{
+			Name:     "foos",
+			ID:       ".test.ListFooResponse.foos",
+			Typez:    11,
+			TypezID:  ".test.Foo",
+			JSONName: "foos",
+			Repeated: true,
+		},
 		Fields: []*api.Field{
 			{
 				Name:     "next_page_token",
@@ -62,11 +62,16 @@ type Message struct {
 	MessageAttributes  []string
 	Name               string
 	QualifiedName      string
+	// The fully qualified name of the message in the source specification,
+	// without the leading dot, e.g. `google.protobuf.Duration`.
+	SourceFQN          string
 	NameSnakeCase      string
 	HasNestedTypes     bool
 	DocLines           []string
 	IsMap              bool
 	IsPageableResponse bool
+	PageableItemName   string
+	PageableItemType   string
 }
 
 type Method struct {
@@ -84,6 +89,7 @@ type Method struct {
 	HasBody           bool
 	BodyAccessor      string
 	IsPageable        bool
+	IsIdempotent      bool
 }
 
 type OneOf struct {
@@ -198,6 +204,7 @@ func newMessage(m *api.Message, c language.Codec, state *api.APIState) *Message
 		MessageAttributes: c.MessageAttributes(m, state),
 		Name:              c.MessageName(m, state),
 		QualifiedName:     c.FQMessageName(m, state),
+		SourceFQN:         strings.TrimPrefix(m.ID, "."),
 		NameSnakeCase:     c.ToSnake(m.Name),
 		HasNestedTypes: func() bool {
 			if len(m.Enums) > 0 || len(m.OneOfs) > 0 {
@@ -213,6 +220,18 @@ func newMessage(m *api.Message, c language.Codec, state *api.APIState) *Message
 		DocLines:           c.FormatDocComments(m.Documentation),
 		IsMap:              m.IsMap,
 		IsPageableResponse: m.IsPageableResponse,
+		PageableItemName: func() string {
+			if m.PageableItem == nil {
+				return ""
+			}
+			return c.ToSnake(m.PageableItem.Name)
+		}(),
+		PageableItemType: func() string {
+			if m.PageableItem == nil {
+				return ""
+			}
+			return c.MethodInOutTypeName(m.PageableItem.TypezID, state)
+		}(),
 	}
 }
 
@@ -233,7 +252,20 @@ func newMethod(m *api.Method, c language.Codec, state *api.APIState) *Method {
 		QueryParams: mapSlice(c.QueryParams(m, state), func(s *api.Field) *Field {
 			return newField(s, c, state)
 		}),
-		IsPageable: m.IsPageable,
+		IsPageable:   m.IsPageable,
+		IsIdempotent: isIdempotent(m.PathInfo.Verb),
+	}
+}
+
+// isIdempotent returns true for the HTTP verbs that AIP-194 considers safe to
+// retry. POST and PATCH requests may have side effects, such as creating a new
+// resource on each call.
+func isIdempotent(verb string) bool {
+	switch strings.ToUpper(verb) {
+	case "GET", "PUT", "DELETE":
+		return true
+	default:
+		return false
 	}
 }
 
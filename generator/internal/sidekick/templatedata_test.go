@@ -0,0 +1,74 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+package sidekick
+
+import (
+	"testing"
+
+	"github.com/googleapis/google-cloud-rust/generator/internal/api"
+	"github.com/googleapis/google-cloud-rust/generator/internal/language"
+)
+
+func TestIsIdempotent(t *testing.T) {
+	var tests = []struct {
+		Verb     string
+		Expected bool
+	}{
+		{"GET", true},
+		{"PUT", true},
+		{"DELETE", true},
+		{"POST", false},
+		{"PATCH", false},
+		{"get", true},
+		{"post", false},
+		{"", false},
+	}
+	for _, test := range tests {
+		if output := isIdempotent(test.Verb); output != test.Expected {
+			t.Errorf("Output %v not equal to expected %v, verb=%q", output, test.Expected, test.Verb)
+		}
+	}
+}
+
+func TestMessageSourceFQN(t *testing.T) {
+	c := &language.RustCodec{
+		ModulePath:                     "model",
+		SourceSpecificationPackageName: "google.cloud.test.v1",
+	}
+	state := &api.APIState{}
+	parent := &api.Message{
+		Name:    "Secret",
+		ID:      ".google.cloud.test.v1.Secret",
+		Package: "google.cloud.test.v1",
+	}
+	nested := &api.Message{
+		Name:    "Replication",
+		ID:      ".google.cloud.test.v1.Secret.Replication",
+		Package: "google.cloud.test.v1",
+		Parent:  parent,
+	}
+	var tests = []struct {
+		Message  *api.Message
+		Expected string
+	}{
+		{parent, "google.cloud.test.v1.Secret"},
+		{nested, "google.cloud.test.v1.Secret.Replication"},
+	}
+	for _, test := range tests {
+		if output := newMessage(test.Message, c, state).SourceFQN; output != test.Expected {
+			t.Errorf("Output %q not equal to expected %q, id=%s", output, test.Expected, test.Message.ID)
+		}
+	}
+}
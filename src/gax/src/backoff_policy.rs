@@ -0,0 +1,322 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::Error;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Determines how long to wait before retrying a failed attempt.
+pub trait BackoffPolicy: Send + Sync + std::fmt::Debug {
+    /// Returns the delay before the next attempt.
+    ///
+    /// # Parameters
+    /// * `loop_start` - when the retry loop started.
+    /// * `attempt_count` - the number of attempts, including the attempt that
+    ///   just failed.
+    /// * `previous_delay` - the delay before the attempt that just failed, or
+    ///   zero if this was the first attempt.
+    fn on_failure(
+        &self,
+        loop_start: Instant,
+        attempt_count: u32,
+        previous_delay: Duration,
+    ) -> Duration;
+}
+
+/// A helper type to use [BackoffPolicy] in client and request options.
+///
+/// Functions that accept a backoff policy take `impl Into<BackoffPolicyArg>`,
+/// so applications can pass any concrete policy or a shared
+/// `Arc<dyn BackoffPolicy>`.
+#[derive(Clone, Debug)]
+pub struct BackoffPolicyArg(Arc<dyn BackoffPolicy>);
+
+impl<T: BackoffPolicy + 'static> From<T> for BackoffPolicyArg {
+    fn from(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl From<Arc<dyn BackoffPolicy>> for BackoffPolicyArg {
+    fn from(value: Arc<dyn BackoffPolicy>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<BackoffPolicyArg> for Arc<dyn BackoffPolicy> {
+    fn from(value: BackoffPolicyArg) -> Self {
+        value.0
+    }
+}
+
+/// The randomization applied to the delays in [ExponentialBackoff].
+///
+/// See [Exponential Backoff and Jitter] for a discussion of these algorithms.
+///
+/// [Exponential Backoff and Jitter]: https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Jitter {
+    /// The delays are not randomized.
+    None,
+    /// The delay is chosen uniformly in `[0, d]`, where `d` is the
+    /// exponentially growing delay for the attempt.
+    #[default]
+    Full,
+    /// The delay is chosen uniformly in `[initial_delay, 3 * previous_delay]`.
+    Decorrelated,
+}
+
+/// Implements truncated exponential backoff with jitter.
+///
+/// Without jitter, the delay after the `n`-th attempt is
+/// `initial_delay * scaling^(n - 1)`, truncated to `maximum_delay`. The
+/// [Jitter] setting controls how this delay is randomized.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_gax::backoff_policy::*;
+/// use std::time::Duration;
+/// let policy = ExponentialBackoffBuilder::new()
+///     .set_initial_delay(Duration::from_millis(100))
+///     .set_maximum_delay(Duration::from_secs(5))
+///     .set_scaling(1.5)
+///     .set_jitter(Jitter::Decorrelated)
+///     .build();
+/// assert!(policy.is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    initial_delay: Duration,
+    maximum_delay: Duration,
+    scaling: f64,
+    jitter: Jitter,
+}
+
+impl ExponentialBackoff {
+    /// The delay after `attempt_count` attempts, before any jitter.
+    fn exponential_delay(&self, attempt_count: u32) -> Duration {
+        let exponent = attempt_count.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let scaling = self.scaling.powi(exponent);
+        if scaling >= self.maximum_delay.as_secs_f64() / self.initial_delay.as_secs_f64() {
+            return self.maximum_delay;
+        }
+        self.initial_delay.mul_f64(scaling).min(self.maximum_delay)
+    }
+}
+
+impl Default for ExponentialBackoff {
+    /// Start with a 1 second delay, doubling up to 60 seconds, with
+    /// [Jitter::Full].
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            maximum_delay: Duration::from_secs(60),
+            scaling: 2.0,
+            jitter: Jitter::Full,
+        }
+    }
+}
+
+impl BackoffPolicy for ExponentialBackoff {
+    fn on_failure(
+        &self,
+        _loop_start: Instant,
+        attempt_count: u32,
+        previous_delay: Duration,
+    ) -> Duration {
+        use rand::Rng;
+        match self.jitter {
+            Jitter::None => self.exponential_delay(attempt_count),
+            Jitter::Full => {
+                let delay = self.exponential_delay(attempt_count);
+                rand::thread_rng().gen_range(Duration::ZERO..=delay)
+            }
+            Jitter::Decorrelated => {
+                let upper = previous_delay
+                    .saturating_mul(3)
+                    .clamp(self.initial_delay, self.maximum_delay);
+                rand::thread_rng().gen_range(self.initial_delay..=upper)
+            }
+        }
+    }
+}
+
+/// A builder for [ExponentialBackoff].
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoffBuilder {
+    initial_delay: Duration,
+    maximum_delay: Duration,
+    scaling: f64,
+    jitter: Jitter,
+}
+
+impl ExponentialBackoffBuilder {
+    /// Creates a builder with the default settings of [ExponentialBackoff].
+    pub fn new() -> Self {
+        let defaults = ExponentialBackoff::default();
+        Self {
+            initial_delay: defaults.initial_delay,
+            maximum_delay: defaults.maximum_delay,
+            scaling: defaults.scaling,
+            jitter: defaults.jitter,
+        }
+    }
+
+    /// Sets the delay after the first failed attempt.
+    pub fn set_initial_delay(mut self, v: Duration) -> Self {
+        self.initial_delay = v;
+        self
+    }
+
+    /// Sets the maximum delay between attempts.
+    pub fn set_maximum_delay(mut self, v: Duration) -> Self {
+        self.maximum_delay = v;
+        self
+    }
+
+    /// Sets the factor applied to the delay after each failed attempt.
+    pub fn set_scaling(mut self, v: f64) -> Self {
+        self.scaling = v;
+        self
+    }
+
+    /// Sets the randomization applied to each delay.
+    pub fn set_jitter(mut self, v: Jitter) -> Self {
+        self.jitter = v;
+        self
+    }
+
+    /// Builds an [ExponentialBackoff], validating the settings.
+    pub fn build(self) -> Result<ExponentialBackoff, Error> {
+        if self.initial_delay.is_zero() {
+            return Err(Error::other("the initial delay must be greater than zero"));
+        }
+        if self.maximum_delay < self.initial_delay {
+            return Err(Error::other(format!(
+                "the maximum delay ({:?}) must be greater than or equal to the initial delay ({:?})",
+                self.maximum_delay, self.initial_delay
+            )));
+        }
+        if !(self.scaling >= 1.0 && self.scaling.is_finite()) {
+            return Err(Error::other(format!(
+                "the scaling factor ({}) must be a finite number greater than or equal to 1.0",
+                self.scaling
+            )));
+        }
+        Ok(ExponentialBackoff {
+            initial_delay: self.initial_delay,
+            maximum_delay: self.maximum_delay,
+            scaling: self.scaling,
+            jitter: self.jitter,
+        })
+    }
+}
+
+impl Default for ExponentialBackoffBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    fn builder() -> ExponentialBackoffBuilder {
+        ExponentialBackoffBuilder::new()
+            .set_initial_delay(Duration::from_secs(1))
+            .set_maximum_delay(Duration::from_secs(20))
+            .set_scaling(2.0)
+    }
+
+    #[test]
+    fn no_jitter() -> Result {
+        let policy = builder().set_jitter(Jitter::None).build()?;
+        let now = Instant::now();
+        let got: Vec<_> = (1..=7)
+            .map(|n| policy.on_failure(now, n, Duration::ZERO).as_secs())
+            .collect();
+        assert_eq!(got, vec![1, 2, 4, 8, 16, 20, 20]);
+        assert_eq!(
+            policy.on_failure(now, u32::MAX, Duration::ZERO),
+            Duration::from_secs(20)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn full_jitter() -> Result {
+        let policy = builder().set_jitter(Jitter::Full).build()?;
+        let now = Instant::now();
+        for n in 1..=10 {
+            let got = policy.on_failure(now, n, Duration::ZERO);
+            assert!(
+                got <= policy.exponential_delay(n),
+                "attempt={n}, got={got:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decorrelated_jitter() -> Result {
+        let policy = builder().set_jitter(Jitter::Decorrelated).build()?;
+        let now = Instant::now();
+        let mut previous = Duration::ZERO;
+        for n in 1..=20 {
+            let got = policy.on_failure(now, n, previous);
+            let upper = (previous * 3).clamp(Duration::from_secs(1), Duration::from_secs(20));
+            assert!(got >= Duration::from_secs(1), "attempt={n}, got={got:?}");
+            assert!(got <= upper, "attempt={n}, got={got:?}, upper={upper:?}");
+            previous = got;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn default() {
+        let policy = ExponentialBackoff::default();
+        let got = policy.on_failure(Instant::now(), 1, Duration::ZERO);
+        assert!(got <= Duration::from_secs(1), "{got:?}");
+        let got = policy.on_failure(Instant::now(), 100, Duration::ZERO);
+        assert!(got <= Duration::from_secs(60), "{got:?}");
+    }
+
+    #[test]
+    fn build_errors() {
+        let got = builder().set_initial_delay(Duration::ZERO).build();
+        assert!(got.is_err(), "{got:?}");
+        let got = builder()
+            .set_initial_delay(Duration::from_secs(10))
+            .set_maximum_delay(Duration::from_secs(5))
+            .build();
+        assert!(got.is_err(), "{got:?}");
+        let got = builder().set_scaling(0.5).build();
+        assert!(got.is_err(), "{got:?}");
+        let got = builder().set_scaling(f64::NAN).build();
+        assert!(got.is_err(), "{got:?}");
+    }
+
+    #[test]
+    fn policy_arg() {
+        let arg: BackoffPolicyArg = ExponentialBackoff::default().into();
+        let policy: Arc<dyn BackoffPolicy> = arg.into();
+        let arg: BackoffPolicyArg = policy.into();
+        let policy = Arc::<dyn BackoffPolicy>::from(arg);
+        let got = policy.on_failure(Instant::now(), 1, Duration::ZERO);
+        assert!(got <= Duration::from_secs(1), "{got:?}");
+    }
+}
@@ -76,7 +76,7 @@ impl Error {
     /// }
     /// ```
     pub fn as_inner<T: std::error::Error + Send + Sync + 'static>(&self) -> Option<&T> {
-        let mut error = self.source.as_ref() as &(dyn std::error::Error);
+        let mut error = self.source.as_ref() as &dyn std::error::Error;
         loop {
             match error.downcast_ref::<T>() {
                 Some(e) => return Some(e),
@@ -84,6 +84,40 @@ impl Error {
             }
         }
     }
+
+    /// Returns the [HttpError] in the source error chain, if any.
+    pub fn http_error(&self) -> Option<&crate::error::HttpError> {
+        self.as_inner::<crate::error::HttpError>()
+    }
+
+    /// Returns the [Code](crate::error::rpc::Code) for errors returned by the
+    /// service.
+    ///
+    /// Returns `None` for errors that did not originate in the service, such
+    /// as I/O or authentication errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use gcp_sdk_gax::error::Error;
+    /// # use gcp_sdk_gax::error::HttpError;
+    /// # use gcp_sdk_gax::error::rpc::Code;
+    /// # use std::collections::HashMap;
+    /// let error: Error = HttpError::new(404, HashMap::new(), None).into();
+    /// assert_eq!(error.code(), Some(Code::NotFound));
+    /// ```
+    pub fn code(&self) -> Option<crate::error::rpc::Code> {
+        self.http_error().map(crate::error::HttpError::code)
+    }
+
+    /// Returns the [Status](crate::error::rpc::Status) returned by the
+    /// service, including its details.
+    ///
+    /// Returns `None` if the error did not originate in the service, or if
+    /// the service response did not include a valid status message.
+    pub fn status(&self) -> Option<crate::error::rpc::Status> {
+        self.http_error().and_then(crate::error::HttpError::status)
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::error::rpc::{Code, Status};
 use bytes::Bytes;
 
 /// An error describing a non-2xx HTTP response.
@@ -52,6 +53,26 @@ impl HttpError {
     pub fn headers(&self) -> &std::collections::HashMap<String, String> {
         &self.headers
     }
+
+    /// Returns the [Status] in the payload, if the payload contains one.
+    ///
+    /// Returns `None` if the payload is empty or it is not a JSON [Status]
+    /// message, as is the case for errors returned by proxies and load
+    /// balancers.
+    pub fn status(&self) -> Option<Status> {
+        Status::try_from(self).ok()
+    }
+
+    /// Returns the [Code] for this error.
+    ///
+    /// The code is taken from the canonical name in the payload, such as
+    /// `"status": "NOT_FOUND"`. If the payload does not include a valid name,
+    /// the code is derived from the HTTP status code.
+    pub fn code(&self) -> Code {
+        self.status()
+            .and_then(|s| s.status.parse::<Code>().ok())
+            .unwrap_or_else(|| Code::from_http_status(self.status_code))
+    }
 }
 
 impl std::fmt::Display for HttpError {
@@ -62,7 +83,7 @@ impl std::fmt::Display for HttpError {
             self.status_code, self.headers
         )?;
         if let Some(payload) = self.payload() {
-            if let Ok(status) = TryInto::<crate::error::rpc::Status>::try_into(payload.clone()) {
+            if let Ok(status) = Status::try_from(payload.clone()) {
                 return write!(f, ", payload:\n{:?}", status);
             }
             write!(f, ", payload:\n{:?}", payload)?;
@@ -44,7 +44,7 @@
 /// ```
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct ErrorInfo {
     /// The reason of the error. This is a constant value that identifies the
@@ -97,6 +97,12 @@ impl ErrorInfo {
     }
 }
 
+impl wkt::Message for ErrorInfo {
+    fn typename() -> &'static str {
+        "google.rpc.ErrorInfo"
+    }
+}
+
 /// Describes when the clients can retry a failed request. Clients could ignore
 /// the recommendation here or retry when this information is missing from error
 /// responses.
@@ -112,7 +118,7 @@ impl ErrorInfo {
 /// reached.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct RetryInfo {
     /// Clients should wait at least this long between retrying the same request.
@@ -127,10 +133,16 @@ impl RetryInfo {
     }
 }
 
+impl wkt::Message for RetryInfo {
+    fn typename() -> &'static str {
+        "google.rpc.RetryInfo"
+    }
+}
+
 /// Describes additional debugging info.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct DebugInfo {
     /// The stack trace entries indicating where the error occurred.
@@ -154,6 +166,12 @@ impl DebugInfo {
     }
 }
 
+impl wkt::Message for DebugInfo {
+    fn typename() -> &'static str {
+        "google.rpc.DebugInfo"
+    }
+}
+
 /// Describes how a quota check failed.
 ///
 /// For example if a daily limit was exceeded for the calling project,
@@ -167,7 +185,7 @@ impl DebugInfo {
 /// quota failure.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct QuotaFailure {
     /// Describes all quota violations.
@@ -185,6 +203,12 @@ impl QuotaFailure {
     }
 }
 
+impl wkt::Message for QuotaFailure {
+    fn typename() -> &'static str {
+        "google.rpc.QuotaFailure"
+    }
+}
+
 /// Defines additional types related to QuotaFailure
 pub mod quota_failure {
 
@@ -192,7 +216,7 @@ pub mod quota_failure {
     /// daily quota or a custom quota that was exceeded.
     #[serde_with::serde_as]
     #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-    #[serde(rename_all = "camelCase")]
+    #[serde(default, rename_all = "camelCase")]
     #[non_exhaustive]
     pub struct Violation {
         /// The subject on which the quota check failed.
@@ -223,6 +247,12 @@ pub mod quota_failure {
             self
         }
     }
+
+    impl wkt::Message for Violation {
+        fn typename() -> &'static str {
+            "google.rpc.QuotaFailure.Violation"
+        }
+    }
 }
 
 /// Describes what preconditions have failed.
@@ -232,7 +262,7 @@ pub mod quota_failure {
 /// PreconditionFailure message.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct PreconditionFailure {
     /// Describes all precondition violations.
@@ -252,13 +282,19 @@ impl PreconditionFailure {
     }
 }
 
+impl wkt::Message for PreconditionFailure {
+    fn typename() -> &'static str {
+        "google.rpc.PreconditionFailure"
+    }
+}
+
 /// Defines additional types related to PreconditionFailure
 pub mod precondition_failure {
 
     /// A message type used to describe a single precondition failure.
     #[serde_with::serde_as]
     #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-    #[serde(rename_all = "camelCase")]
+    #[serde(default, rename_all = "camelCase")]
     #[non_exhaustive]
     pub struct Violation {
         /// The type of PreconditionFailure. We recommend using a service-specific
@@ -298,13 +334,19 @@ pub mod precondition_failure {
             self
         }
     }
+
+    impl wkt::Message for Violation {
+        fn typename() -> &'static str {
+            "google.rpc.PreconditionFailure.Violation"
+        }
+    }
 }
 
 /// Describes violations in a client request. This error type focuses on the
 /// syntactic aspects of the request.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct BadRequest {
     /// Describes all violations in a client request.
@@ -324,13 +366,19 @@ impl BadRequest {
     }
 }
 
+impl wkt::Message for BadRequest {
+    fn typename() -> &'static str {
+        "google.rpc.BadRequest"
+    }
+}
+
 /// Defines additional types related to BadRequest
 pub mod bad_request {
 
     /// A message type used to describe a single bad request field.
     #[serde_with::serde_as]
     #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-    #[serde(rename_all = "camelCase")]
+    #[serde(default, rename_all = "camelCase")]
     #[non_exhaustive]
     pub struct FieldViolation {
         /// A path that leads to a field in the request body. The value will be a
@@ -395,13 +443,19 @@ pub mod bad_request {
             self
         }
     }
+
+    impl wkt::Message for FieldViolation {
+        fn typename() -> &'static str {
+            "google.rpc.BadRequest.FieldViolation"
+        }
+    }
 }
 
 /// Contains metadata about the request that clients can attach when filing a bug
 /// or providing other forms of feedback.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct RequestInfo {
     /// An opaque string that should only be interpreted by the service generating
@@ -427,10 +481,16 @@ impl RequestInfo {
     }
 }
 
+impl wkt::Message for RequestInfo {
+    fn typename() -> &'static str {
+        "google.rpc.RequestInfo"
+    }
+}
+
 /// Describes the resource that is being accessed.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct ResourceInfo {
     /// A name for the type of resource being accessed, e.g. "sql table",
@@ -481,6 +541,12 @@ impl ResourceInfo {
     }
 }
 
+impl wkt::Message for ResourceInfo {
+    fn typename() -> &'static str {
+        "google.rpc.ResourceInfo"
+    }
+}
+
 /// Provides links to documentation or for performing an out of band action.
 ///
 /// For example, if a quota check failed with an error indicating the calling
@@ -488,7 +554,7 @@ impl ResourceInfo {
 /// directly to the right place in the developer console to flip the bit.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct Help {
     /// URL(s) pointing to additional information on handling the current error.
@@ -506,13 +572,19 @@ impl Help {
     }
 }
 
+impl wkt::Message for Help {
+    fn typename() -> &'static str {
+        "google.rpc.Help"
+    }
+}
+
 /// Defines additional types related to Help
 pub mod help {
 
     /// Describes a URL link.
     #[serde_with::serde_as]
     #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-    #[serde(rename_all = "camelCase")]
+    #[serde(default, rename_all = "camelCase")]
     #[non_exhaustive]
     pub struct Link {
         /// Describes what the link offers.
@@ -535,13 +607,19 @@ pub mod help {
             self
         }
     }
+
+    impl wkt::Message for Link {
+        fn typename() -> &'static str {
+            "google.rpc.Help.Link"
+        }
+    }
 }
 
 /// Provides a localized error message that is safe to return to the user
 /// which can be attached to an RPC error.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[serde(default, rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct LocalizedMessage {
     /// The locale used following the specification defined at
@@ -566,3 +644,9 @@ impl LocalizedMessage {
         self
     }
 }
+
+impl wkt::Message for LocalizedMessage {
+    fn typename() -> &'static str {
+        "google.rpc.LocalizedMessage"
+    }
+}
@@ -40,6 +40,13 @@ pub struct Status {
     /// [Status] `details` field, or localized by the client.
     pub message: String,
 
+    /// The canonical name of the error code, for example `NOT_FOUND`.
+    ///
+    /// Services using a HTTP transport include this field, as `code` contains
+    /// the HTTP status code. Use [str::parse] to convert it to a [Code].
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub status: String,
+
     /// A list of messages that carry the error details.  There is a common set
     /// of message types for APIs to use.
     pub details: Vec<StatusDetails>,
@@ -51,7 +58,7 @@ pub struct Status {
 /// the most specific error code that applies.  For example, prefer
 /// `OUT_OF_RANGE` over `FAILED_PRECONDITION` if both codes apply.
 /// Similarly prefer `NOT_FOUND` or `ALREADY_EXISTS` over `FAILED_PRECONDITION`.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 #[non_exhaustive]
 pub enum Code {
     /// Not an error; returned on success.
@@ -71,6 +78,7 @@ pub enum Code {
     /// may be converted to this error.
     ///
     /// HTTP Mapping: 500 Internal Server Error
+    #[default]
     Unknown = 2,
 
     /// The client specified an invalid argument.  Note that this differs
@@ -210,27 +218,102 @@ pub enum Code {
     Unauthenticated = 16,
 }
 
-impl Default for Code {
-    fn default() -> Self {
-        Self::Unknown
+impl Code {
+    /// Returns the canonical name of the code, for example `"NOT_FOUND"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Code::Ok => "OK",
+            Code::Canceled => "CANCELLED",
+            Code::Unknown => "UNKNOWN",
+            Code::InvalidArgument => "INVALID_ARGUMENT",
+            Code::DeadlineExceeded => "DEADLINE_EXCEEDED",
+            Code::NotFound => "NOT_FOUND",
+            Code::AlreadyExists => "ALREADY_EXISTS",
+            Code::PermissionDenied => "PERMISSION_DENIED",
+            Code::ResourceExhausted => "RESOURCE_EXHAUSTED",
+            Code::FailedPrecondition => "FAILED_PRECONDITION",
+            Code::Aborted => "ABORTED",
+            Code::OutOfRange => "OUT_OF_RANGE",
+            Code::Unimplemented => "UNIMPLEMENTED",
+            Code::Internal => "INTERNAL",
+            Code::Unavailable => "UNAVAILABLE",
+            Code::DataLoss => "DATA_LOSS",
+            Code::Unauthenticated => "UNAUTHENTICATED",
+        }
     }
-}
 
-impl Serialize for Code {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_i32(self.clone() as i32)
+    /// Returns the HTTP status code corresponding to this [Code].
+    ///
+    /// This follows the HTTP mapping documented for each code. Where more than
+    /// one code maps to the same HTTP status code, the mapping is not
+    /// reversible.
+    pub fn http_status_code(&self) -> u16 {
+        match self {
+            Code::Ok => 200,
+            Code::Canceled => 499,
+            Code::Unknown => 500,
+            Code::InvalidArgument => 400,
+            Code::DeadlineExceeded => 504,
+            Code::NotFound => 404,
+            Code::AlreadyExists => 409,
+            Code::PermissionDenied => 403,
+            Code::ResourceExhausted => 429,
+            Code::FailedPrecondition => 400,
+            Code::Aborted => 409,
+            Code::OutOfRange => 400,
+            Code::Unimplemented => 501,
+            Code::Internal => 500,
+            Code::Unavailable => 503,
+            Code::DataLoss => 500,
+            Code::Unauthenticated => 401,
+        }
+    }
+
+    /// Returns the [Code] corresponding to an HTTP status code.
+    ///
+    /// Services that use HTTP return their errors with the HTTP status code
+    /// in the documented mapping for each [Code]. Where multiple codes share
+    /// the same HTTP status code, this returns the most general one. Unlisted
+    /// status codes map to [Code::Ok], [Code::FailedPrecondition],
+    /// [Code::Internal] or [Code::Unknown] based on their class.
+    pub fn from_http_status(status_code: u16) -> Self {
+        match status_code {
+            400 => Code::InvalidArgument,
+            401 => Code::Unauthenticated,
+            403 => Code::PermissionDenied,
+            404 => Code::NotFound,
+            409 => Code::Aborted,
+            416 => Code::OutOfRange,
+            429 => Code::ResourceExhausted,
+            499 => Code::Canceled,
+            501 => Code::Unimplemented,
+            503 => Code::Unavailable,
+            504 => Code::DeadlineExceeded,
+            _ => match status_code / 100 {
+                2 => Code::Ok,
+                4 => Code::FailedPrecondition,
+                5 => Code::Internal,
+                _ => Code::Unknown,
+            },
+        }
     }
 }
 
-impl<'de> Deserialize<'de> for Code {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        match i32::deserialize(deserializer)? {
+/// The error returned when converting invalid values into a [Code].
+#[derive(thiserror::Error, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum CodeError {
+    #[error("{0} is not a valid status code")]
+    InvalidValue(i32),
+    #[error("{0:?} is not a valid status code name")]
+    InvalidName(String),
+}
+
+impl TryFrom<i32> for Code {
+    type Error = CodeError;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
             0 => Ok(Code::Ok),
             1 => Ok(Code::Canceled),
             2 => Ok(Code::Unknown),
@@ -248,11 +331,71 @@ impl<'de> Deserialize<'de> for Code {
             14 => Ok(Code::Unavailable),
             15 => Ok(Code::DataLoss),
             16 => Ok(Code::Unauthenticated),
-            _ => Ok(Code::default()),
+            _ => Err(CodeError::InvalidValue(value)),
+        }
+    }
+}
+
+impl From<Code> for i32 {
+    fn from(value: Code) -> Self {
+        value as i32
+    }
+}
+
+impl std::str::FromStr for Code {
+    type Err = CodeError;
+
+    /// Parses the canonical name of a code, for example `"NOT_FOUND"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "OK" => Ok(Code::Ok),
+            "CANCELLED" => Ok(Code::Canceled),
+            "UNKNOWN" => Ok(Code::Unknown),
+            "INVALID_ARGUMENT" => Ok(Code::InvalidArgument),
+            "DEADLINE_EXCEEDED" => Ok(Code::DeadlineExceeded),
+            "NOT_FOUND" => Ok(Code::NotFound),
+            "ALREADY_EXISTS" => Ok(Code::AlreadyExists),
+            "PERMISSION_DENIED" => Ok(Code::PermissionDenied),
+            "RESOURCE_EXHAUSTED" => Ok(Code::ResourceExhausted),
+            "FAILED_PRECONDITION" => Ok(Code::FailedPrecondition),
+            "ABORTED" => Ok(Code::Aborted),
+            "OUT_OF_RANGE" => Ok(Code::OutOfRange),
+            "UNIMPLEMENTED" => Ok(Code::Unimplemented),
+            "INTERNAL" => Ok(Code::Internal),
+            "UNAVAILABLE" => Ok(Code::Unavailable),
+            "DATA_LOSS" => Ok(Code::DataLoss),
+            "UNAUTHENTICATED" => Ok(Code::Unauthenticated),
+            _ => Err(CodeError::InvalidName(s.to_string())),
         }
     }
 }
 
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Code {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i32(*self as i32)
+    }
+}
+
+impl<'de> Deserialize<'de> for Code {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+        let value = i32::deserialize(deserializer)?;
+        Code::try_from(value).map_err(D::Error::custom)
+    }
+}
+
 /// A helper class to deserialized wrapped Status messages.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 struct ErrorWrapper {
@@ -263,8 +406,18 @@ impl TryFrom<HttpError> for Status {
     type Error = Error;
 
     fn try_from(value: HttpError) -> Result<Self, Self::Error> {
-        let wrapper: ErrorWrapper =
-            serde_json::from_slice(value.payload().unwrap()).map_err(Error::serde)?;
+        Status::try_from(&value)
+    }
+}
+
+impl TryFrom<&HttpError> for Status {
+    type Error = Error;
+
+    fn try_from(value: &HttpError) -> Result<Self, Self::Error> {
+        let payload = value
+            .payload()
+            .ok_or_else(|| Error::serde("the HTTP error has no payload"))?;
+        let wrapper: ErrorWrapper = serde_json::from_slice(payload).map_err(Error::serde)?;
         Ok(wrapper.error)
     }
 }
@@ -279,8 +432,12 @@ impl TryFrom<bytes::Bytes> for Status {
 }
 
 /// The type of details associated with [Status].
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-#[serde(rename_all = "camelCase", untagged)]
+///
+/// In JSON, each detail is an `Any` message. The variant is selected by its
+/// `@type` field, for example, `type.googleapis.com/google.rpc.RetryInfo`.
+/// Details with an unknown type, or that cannot be parsed as their type, are
+/// returned as [StatusDetails::Other].
+#[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum StatusDetails {
     BadRequest(BadRequest),
@@ -302,6 +459,79 @@ impl Default for StatusDetails {
     }
 }
 
+impl Serialize for StatusDetails {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        fn with_type<S, T>(serializer: S, message: &T) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+            T: wkt::Message,
+        {
+            use serde::ser::Error as _;
+            wkt::Any::from_msg(message)
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        }
+        match self {
+            Self::BadRequest(m) => with_type(serializer, m),
+            Self::DebugInfo(m) => with_type(serializer, m),
+            Self::ErrorInfo(m) => with_type(serializer, m),
+            Self::Help(m) => with_type(serializer, m),
+            Self::LocalizedMessage(m) => with_type(serializer, m),
+            Self::PreconditionFailure(m) => with_type(serializer, m),
+            Self::QuotaFailure(m) => with_type(serializer, m),
+            Self::RequestInfo(m) => with_type(serializer, m),
+            Self::ResourceInfo(m) => with_type(serializer, m),
+            Self::RetryInfo(m) => with_type(serializer, m),
+            Self::Other(any) => any.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusDetails {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error as _;
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let name = value
+            .get("@type")
+            .and_then(serde_json::Value::as_str)
+            .and_then(|t| t.rsplit_once('/'))
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_default();
+        fn typed<T, F>(value: &serde_json::Value, f: F) -> Option<StatusDetails>
+        where
+            T: serde::de::DeserializeOwned,
+            F: FnOnce(T) -> StatusDetails,
+        {
+            serde_json::from_value::<T>(value.clone()).ok().map(f)
+        }
+        let details = match name.as_str() {
+            "google.rpc.BadRequest" => typed(&value, Self::BadRequest),
+            "google.rpc.DebugInfo" => typed(&value, Self::DebugInfo),
+            "google.rpc.ErrorInfo" => typed(&value, Self::ErrorInfo),
+            "google.rpc.Help" => typed(&value, Self::Help),
+            "google.rpc.LocalizedMessage" => typed(&value, Self::LocalizedMessage),
+            "google.rpc.PreconditionFailure" => typed(&value, Self::PreconditionFailure),
+            "google.rpc.QuotaFailure" => typed(&value, Self::QuotaFailure),
+            "google.rpc.RequestInfo" => typed(&value, Self::RequestInfo),
+            "google.rpc.ResourceInfo" => typed(&value, Self::ResourceInfo),
+            "google.rpc.RetryInfo" => typed(&value, Self::RetryInfo),
+            _ => None,
+        };
+        match details {
+            Some(d) => Ok(d),
+            None => serde_json::from_value::<wkt::Any>(value)
+                .map(Self::Other)
+                .map_err(D::Error::custom),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -313,6 +543,7 @@ mod test {
         let status = Status {
             code: 12,
             message: "test".to_string(),
+            status: "UNIMPLEMENTED".to_string(),
 
             details: vec![
                 StatusDetails::BadRequest(BadRequest {
@@ -372,17 +603,18 @@ mod test {
         let want = json!({
             "code": 12,
             "message": "test",
+            "status": "UNIMPLEMENTED",
             "details": [
-                {"fieldViolations": [{"field": "field", "description": "desc"}]},
-                {"stackEntries": ["stack"], "detail": "detail"},
-                {"reason": "reason", "domain": "domain"},
-                {"links": [{"description": "desc", "url": "url"}]},
-                {"locale": "locale", "message": "message"},
-                {"violations": [{"type": "type", "subject": "subject", "description": "desc"}]},
-                {"violations": [{"subject": "subject", "description": "desc"}]},
-                {"requestId": "id", "servingData": "data"},
-                {"resourceType": "type", "resourceName": "name", "owner": "owner", "description": "desc"},
-                {"retryDelay": "1s"},
+                {"@type": "type.googleapis.com/google.rpc.BadRequest", "fieldViolations": [{"field": "field", "description": "desc"}]},
+                {"@type": "type.googleapis.com/google.rpc.DebugInfo", "stackEntries": ["stack"], "detail": "detail"},
+                {"@type": "type.googleapis.com/google.rpc.ErrorInfo", "reason": "reason", "domain": "domain"},
+                {"@type": "type.googleapis.com/google.rpc.Help", "links": [{"description": "desc", "url": "url"}]},
+                {"@type": "type.googleapis.com/google.rpc.LocalizedMessage", "locale": "locale", "message": "message"},
+                {"@type": "type.googleapis.com/google.rpc.PreconditionFailure", "violations": [{"type": "type", "subject": "subject", "description": "desc"}]},
+                {"@type": "type.googleapis.com/google.rpc.QuotaFailure", "violations": [{"subject": "subject", "description": "desc"}]},
+                {"@type": "type.googleapis.com/google.rpc.RequestInfo", "requestId": "id", "servingData": "data"},
+                {"@type": "type.googleapis.com/google.rpc.ResourceInfo", "resourceType": "type", "resourceName": "name", "owner": "owner", "description": "desc"},
+                {"@type": "type.googleapis.com/google.rpc.RetryInfo", "retryDelay": "1s"},
             ]
         });
         assert_eq!(got, want);
@@ -394,22 +626,23 @@ mod test {
             "code": 20,
             "message": "test",
             "details": [
-                {"fieldViolations": [{"field": "field", "description": "desc"}]},
-                {"stackEntries": ["stack"], "detail": "detail"},
-                {"reason": "reason", "domain": "domain", "metadata": {}},
-                {"links": [{"description": "desc", "url": "url"}]},
-                {"locale": "locale", "message": "message"},
-                {"violations": [{"type": "type", "subject": "subject", "description": "desc"}]},
-                {"violations": [{"subject": "subject", "description": "desc"}]},
-                {"requestId": "id", "servingData": "data"},
-                {"resourceType": "type", "resourceName": "name", "owner": "owner", "description": "desc"},
-                {"retryDelay": "1s"},
+                {"@type": "type.googleapis.com/google.rpc.BadRequest", "fieldViolations": [{"field": "field", "description": "desc"}]},
+                {"@type": "type.googleapis.com/google.rpc.DebugInfo", "stackEntries": ["stack"], "detail": "detail"},
+                {"@type": "type.googleapis.com/google.rpc.ErrorInfo", "reason": "reason", "domain": "domain", "metadata": {}},
+                {"@type": "type.googleapis.com/google.rpc.Help", "links": [{"description": "desc", "url": "url"}]},
+                {"@type": "type.googleapis.com/google.rpc.LocalizedMessage", "locale": "locale", "message": "message"},
+                {"@type": "type.googleapis.com/google.rpc.PreconditionFailure", "violations": [{"type": "type", "subject": "subject", "description": "desc"}]},
+                {"@type": "type.googleapis.com/google.rpc.QuotaFailure", "violations": [{"subject": "subject", "description": "desc"}]},
+                {"@type": "type.googleapis.com/google.rpc.RequestInfo", "requestId": "id", "servingData": "data"},
+                {"@type": "type.googleapis.com/google.rpc.ResourceInfo", "resourceType": "type", "resourceName": "name", "owner": "owner", "description": "desc"},
+                {"@type": "type.googleapis.com/google.rpc.RetryInfo", "retryDelay": "1s"},
             ]
         });
         let got: Status = serde_json::from_value(json).unwrap();
         let want = Status {
             code: 20,
             message: "test".to_string(),
+            status: String::new(),
             details: vec![
                 StatusDetails::BadRequest(BadRequest {
                     field_violations: vec![bad_request::FieldViolation {
@@ -480,6 +713,7 @@ mod test {
                 message:
                     "The provided Secret ID [] does not match the expected format [[a-zA-Z_0-9]+]"
                         .into(),
+                status: "INVALID_ARGUMENT".into(),
                 details: [].into(),
             },
         };
@@ -493,8 +727,166 @@ mod test {
             code: 400,
             message: "The provided Secret ID [] does not match the expected format [[a-zA-Z_0-9]+]"
                 .into(),
+            status: "INVALID_ARGUMENT".into(),
             details: [].into(),
         };
         assert_eq!(got, want);
     }
+
+    #[test]
+    fn deserialize_overlapping_shapes() {
+        // `PreconditionFailure` and `QuotaFailure` violations have overlapping
+        // fields, only the `@type` can distinguish them.
+        let json = json!({
+            "code": 9,
+            "details": [
+                {
+                    "@type": "type.googleapis.com/google.rpc.QuotaFailure",
+                    "violations": [{"subject": "subject", "description": "desc"}]
+                },
+                {
+                    "@type": "type.googleapis.com/google.rpc.PreconditionFailure",
+                    "violations": [{"subject": "subject", "description": "desc"}]
+                },
+            ]
+        });
+        let got: Status = serde_json::from_value(json).unwrap();
+        let want = vec![
+            StatusDetails::QuotaFailure(QuotaFailure {
+                violations: vec![quota_failure::Violation {
+                    subject: "subject".to_string(),
+                    description: "desc".to_string(),
+                }],
+            }),
+            StatusDetails::PreconditionFailure(PreconditionFailure {
+                violations: vec![precondition_failure::Violation {
+                    r#type: String::new(),
+                    subject: "subject".to_string(),
+                    description: "desc".to_string(),
+                }],
+            }),
+        ];
+        assert_eq!(got.details, want);
+    }
+
+    #[test]
+    fn deserialize_missing_fields() {
+        let json = json!({
+            "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+            "reason": "reason"
+        });
+        let got: StatusDetails = serde_json::from_value(json).unwrap();
+        let want = StatusDetails::ErrorInfo(ErrorInfo {
+            reason: "reason".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn deserialize_other() {
+        let inputs = [
+            json!({"@type": "type.googleapis.com/google.rpc.Unknown", "field": "value"}),
+            json!({"field": "value"}),
+            json!({"@type": "type.googleapis.com/google.rpc.RetryInfo", "retryDelay": 42}),
+        ];
+        for input in inputs {
+            let got: StatusDetails = serde_json::from_value(input.clone()).unwrap();
+            let want: wkt::Any = serde_json::from_value(input.clone()).unwrap();
+            assert_eq!(got, StatusDetails::Other(want), "{input:?}");
+            let roundtrip = serde_json::to_value(&got).unwrap();
+            assert_eq!(roundtrip, input);
+        }
+    }
+
+    #[test]
+    fn roundtrip_typed() {
+        let input = StatusDetails::RetryInfo(RetryInfo {
+            retry_delay: Some(wkt::Duration::clamp(5, 0)),
+        });
+        let json = serde_json::to_value(&input).unwrap();
+        assert_eq!(
+            json,
+            json!({"@type": "type.googleapis.com/google.rpc.RetryInfo", "retryDelay": "5s"})
+        );
+        let got: StatusDetails = serde_json::from_value(json).unwrap();
+        assert_eq!(got, input);
+    }
+
+    #[test]
+    fn code_names() {
+        for value in 0..=16 {
+            let code = Code::try_from(value).unwrap();
+            let name = code.as_str();
+            assert_eq!(name.parse::<Code>(), Ok(code), "{name}");
+            assert_eq!(code.to_string(), name);
+            assert_eq!(i32::from(code), value);
+        }
+        assert_eq!("NOT_FOUND".parse::<Code>(), Ok(Code::NotFound));
+        assert_eq!("CANCELLED".parse::<Code>(), Ok(Code::Canceled));
+        assert_eq!(
+            "not_found".parse::<Code>(),
+            Err(CodeError::InvalidName("not_found".to_string()))
+        );
+        assert!("".parse::<Code>().is_err());
+    }
+
+    #[test]
+    fn code_try_from() {
+        assert_eq!(Code::try_from(5), Ok(Code::NotFound));
+        assert_eq!(Code::try_from(17), Err(CodeError::InvalidValue(17)));
+        assert_eq!(Code::try_from(-1), Err(CodeError::InvalidValue(-1)));
+    }
+
+    #[test]
+    fn code_serde() {
+        let got = serde_json::to_value(Code::Unavailable).unwrap();
+        assert_eq!(got, json!(14));
+        let got = serde_json::from_value::<Code>(json!(5)).unwrap();
+        assert_eq!(got, Code::NotFound);
+        let got = serde_json::from_value::<Code>(json!(42));
+        assert!(got.is_err(), "{got:?}");
+    }
+
+    #[test_case::test_case(Code::Ok, 200)]
+    #[test_case::test_case(Code::Canceled, 499)]
+    #[test_case::test_case(Code::InvalidArgument, 400)]
+    #[test_case::test_case(Code::DeadlineExceeded, 504)]
+    #[test_case::test_case(Code::NotFound, 404)]
+    #[test_case::test_case(Code::Aborted, 409)]
+    #[test_case::test_case(Code::PermissionDenied, 403)]
+    #[test_case::test_case(Code::ResourceExhausted, 429)]
+    #[test_case::test_case(Code::Unimplemented, 501)]
+    #[test_case::test_case(Code::Unavailable, 503)]
+    #[test_case::test_case(Code::Unauthenticated, 401)]
+    fn http_roundtrip(code: Code, status: u16) {
+        assert_eq!(code.http_status_code(), status);
+        assert_eq!(Code::from_http_status(status), code);
+    }
+
+    #[test_case::test_case(Code::Unknown, 500, Code::Internal)]
+    #[test_case::test_case(Code::AlreadyExists, 409, Code::Aborted)]
+    #[test_case::test_case(Code::FailedPrecondition, 400, Code::InvalidArgument)]
+    #[test_case::test_case(Code::OutOfRange, 400, Code::InvalidArgument)]
+    #[test_case::test_case(Code::Internal, 500, Code::Internal)]
+    #[test_case::test_case(Code::DataLoss, 500, Code::Internal)]
+    fn http_shared_status(code: Code, status: u16, back: Code) {
+        assert_eq!(code.http_status_code(), status);
+        assert_eq!(Code::from_http_status(status), back);
+    }
+
+    #[test_case::test_case(204, Code::Ok)]
+    #[test_case::test_case(418, Code::FailedPrecondition)]
+    #[test_case::test_case(502, Code::Internal)]
+    #[test_case::test_case(302, Code::Unknown)]
+    fn http_unlisted(status: u16, code: Code) {
+        assert_eq!(Code::from_http_status(status), code);
+    }
+
+    #[test]
+    fn code_hash() {
+        let set = std::collections::HashSet::from([Code::Unavailable, Code::Aborted]);
+        assert!(set.contains(&Code::Unavailable));
+        assert!(!set.contains(&Code::NotFound));
+    }
 }
@@ -67,3 +67,28 @@ pub mod error;
 /// Async streams are not yet stable, so neither is the use of this feature.
 #[cfg(feature = "stream")]
 pub mod paginator;
+
+/// Defines traits and types to control how RPCs are retried.
+///
+/// Generated clients send each request once, unless the application configures
+/// a [RetryPolicy](crate::retry_policy::RetryPolicy) in the client
+/// configuration or in the [RequestOptions](crate::options::RequestOptions)
+/// for a single request. Policies can be composed, for example, to retry
+/// `UNAVAILABLE` errors for at most 5 attempts or 30 seconds.
+pub mod retry_policy;
+
+/// Defines traits and types to control the delay between retry attempts.
+///
+/// The retry loop waits between attempts according to a
+/// [BackoffPolicy](crate::backoff_policy::BackoffPolicy). If the service
+/// returns a `RetryInfo` error detail, its `retry_delay` is used instead.
+pub mod backoff_policy;
+
+/// Types to configure individual requests.
+pub mod options;
+
+/// Implementation details for the generated clients. The retry loop is shared
+/// by all generated clients, but it is not intended for general use.
+#[cfg(feature = "sdk_client")]
+#[doc(hidden)]
+pub mod retry_loop_internal;
@@ -0,0 +1,265 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::backoff_policy::{BackoffPolicy, BackoffPolicyArg};
+use crate::retry_policy::{RetryPolicy, RetryPolicyArg};
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A set of options configuring a single request.
+///
+/// Each generated client method receives a [RequestOptions]. Any option set
+/// here overrides the corresponding client-level configuration for that
+/// request only. Generated clients also accept a [RequestOptions] in their
+/// configuration, with the defaults for all requests, see
+/// [merge][RequestOptions::merge] for details.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_gax::options::RequestOptions;
+/// # use gcp_sdk_gax::retry_policy::LimitedAttemptCount;
+/// use std::time::Duration;
+/// let options = RequestOptions::default()
+///     .set_retry_policy(LimitedAttemptCount::new(3))
+///     .set_attempt_timeout(Duration::from_millis(500))
+///     .set_overall_timeout(Duration::from_secs(2));
+/// assert!(options.retry_policy().is_some());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RequestOptions {
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    backoff_policy: Option<Arc<dyn BackoffPolicy>>,
+    idempotent: Option<bool>,
+    attempt_timeout: Option<Duration>,
+    overall_timeout: Option<Duration>,
+    extra_headers: HeaderMap,
+    user_project: Option<String>,
+}
+
+impl RequestOptions {
+    /// Sets the retry policy for this request.
+    pub fn set_retry_policy<V: Into<RetryPolicyArg>>(mut self, v: V) -> Self {
+        let v: RetryPolicyArg = v.into();
+        self.retry_policy = Some(v.into());
+        self
+    }
+
+    /// Returns the retry policy override, if any.
+    pub fn retry_policy(&self) -> &Option<Arc<dyn RetryPolicy>> {
+        &self.retry_policy
+    }
+
+    /// Sets the backoff policy for this request.
+    pub fn set_backoff_policy<V: Into<BackoffPolicyArg>>(mut self, v: V) -> Self {
+        let v: BackoffPolicyArg = v.into();
+        self.backoff_policy = Some(v.into());
+        self
+    }
+
+    /// Returns the backoff policy override, if any.
+    pub fn backoff_policy(&self) -> &Option<Arc<dyn BackoffPolicy>> {
+        &self.backoff_policy
+    }
+
+    /// Treats the request as idempotent, or not, regardless of its method.
+    ///
+    /// Each generated method has a default idempotency, based on its HTTP
+    /// method. For example, `GET` requests are idempotent, and `POST` requests
+    /// are not. Retry policies do not retry non-idempotent requests, so
+    /// applications can use this option when they know a request is safe to
+    /// retry, for example, because it includes a request id.
+    pub fn set_idempotency(mut self, v: bool) -> Self {
+        self.idempotent = Some(v);
+        self
+    }
+
+    /// Sets the idempotency, unless it has already been set.
+    ///
+    /// Generated clients use this function to apply the default idempotency
+    /// of each method. It is not intended for general use.
+    #[doc(hidden)]
+    pub fn set_default_idempotency(mut self, v: bool) -> Self {
+        self.idempotent.get_or_insert(v);
+        self
+    }
+
+    /// Returns the idempotency of the request, if known.
+    pub fn idempotent(&self) -> Option<bool> {
+        self.idempotent
+    }
+
+    /// Sets the maximum time for each attempt of this request.
+    ///
+    /// An attempt that exceeds this timeout fails, and may be retried
+    /// depending on the retry policy.
+    pub fn set_attempt_timeout(mut self, v: Duration) -> Self {
+        self.attempt_timeout = Some(v);
+        self
+    }
+
+    /// Returns the attempt timeout, if any.
+    pub fn attempt_timeout(&self) -> Option<Duration> {
+        self.attempt_timeout
+    }
+
+    /// Sets the maximum time for this request, including all retry attempts
+    /// and the backoff delays between them.
+    pub fn set_overall_timeout(mut self, v: Duration) -> Self {
+        self.overall_timeout = Some(v);
+        self
+    }
+
+    /// Returns the overall timeout, if any.
+    pub fn overall_timeout(&self) -> Option<Duration> {
+        self.overall_timeout
+    }
+
+    /// Adds a header to this request, replacing any previous value for the
+    /// same header.
+    pub fn insert_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.extra_headers.insert(name, value);
+        self
+    }
+
+    /// Returns the headers added to this request.
+    pub fn extra_headers(&self) -> &HeaderMap {
+        &self.extra_headers
+    }
+
+    /// Sets the project used for quota and billing of this request.
+    ///
+    /// This is sent as the `x-goog-user-project` header, and overrides the
+    /// quota project associated with the client credentials, if any.
+    pub fn set_user_project<T: Into<String>>(mut self, v: T) -> Self {
+        self.user_project = Some(v.into());
+        self
+    }
+
+    /// Returns the quota project override, if any.
+    pub fn user_project(&self) -> &Option<String> {
+        &self.user_project
+    }
+
+    /// Merges these options over `defaults`.
+    ///
+    /// Options set in `self` take precedence, any options not set in `self`
+    /// are taken from `defaults`. Headers are combined, if both contain the
+    /// same header, only the values in `self` are kept.
+    pub fn merge(mut self, defaults: &RequestOptions) -> Self {
+        fn or<T: Clone>(v: &mut Option<T>, default: &Option<T>) {
+            if v.is_none() {
+                v.clone_from(default);
+            }
+        }
+        or(&mut self.retry_policy, &defaults.retry_policy);
+        or(&mut self.backoff_policy, &defaults.backoff_policy);
+        or(&mut self.idempotent, &defaults.idempotent);
+        or(&mut self.attempt_timeout, &defaults.attempt_timeout);
+        or(&mut self.overall_timeout, &defaults.overall_timeout);
+        or(&mut self.user_project, &defaults.user_project);
+        for (name, value) in defaults.extra_headers.iter() {
+            if !self.extra_headers.contains_key(name) {
+                self.extra_headers.append(name, value.clone());
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idempotency() {
+        let options = RequestOptions::default();
+        assert_eq!(options.idempotent(), None);
+        let options = options.set_default_idempotency(true);
+        assert_eq!(options.idempotent(), Some(true));
+
+        let options = RequestOptions::default()
+            .set_idempotency(true)
+            .set_default_idempotency(false);
+        assert_eq!(options.idempotent(), Some(true));
+        let options = RequestOptions::default()
+            .set_idempotency(false)
+            .set_default_idempotency(true);
+        assert_eq!(options.idempotent(), Some(false));
+    }
+
+    #[test]
+    fn timeouts() {
+        let options = RequestOptions::default();
+        assert_eq!(options.attempt_timeout(), None);
+        assert_eq!(options.overall_timeout(), None);
+        let options = options
+            .set_attempt_timeout(Duration::from_secs(1))
+            .set_overall_timeout(Duration::from_secs(5));
+        assert_eq!(options.attempt_timeout(), Some(Duration::from_secs(1)));
+        assert_eq!(options.overall_timeout(), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn headers_and_project() {
+        let options = RequestOptions::default()
+            .insert_header(
+                HeaderName::from_static("x-goog-request-params"),
+                HeaderValue::from_static("a=b"),
+            )
+            .set_user_project("my-project");
+        assert_eq!(
+            options.extra_headers().get("x-goog-request-params"),
+            Some(&HeaderValue::from_static("a=b"))
+        );
+        assert_eq!(options.user_project().as_deref(), Some("my-project"));
+    }
+
+    #[test]
+    fn merge() {
+        let defaults = RequestOptions::default()
+            .set_retry_policy(crate::retry_policy::NeverRetry)
+            .set_attempt_timeout(Duration::from_secs(10))
+            .set_overall_timeout(Duration::from_secs(60))
+            .set_user_project("default-project")
+            .insert_header(
+                HeaderName::from_static("x-a"),
+                HeaderValue::from_static("default-a"),
+            )
+            .insert_header(
+                HeaderName::from_static("x-b"),
+                HeaderValue::from_static("default-b"),
+            );
+        let options = RequestOptions::default()
+            .set_overall_timeout(Duration::from_secs(2))
+            .insert_header(
+                HeaderName::from_static("x-b"),
+                HeaderValue::from_static("call-b"),
+            )
+            .merge(&defaults);
+        assert!(options.retry_policy().is_some());
+        assert!(options.backoff_policy().is_none());
+        assert_eq!(options.idempotent(), None);
+        assert_eq!(options.attempt_timeout(), Some(Duration::from_secs(10)));
+        assert_eq!(options.overall_timeout(), Some(Duration::from_secs(2)));
+        assert_eq!(options.user_project().as_deref(), Some("default-project"));
+        let headers = options.extra_headers();
+        assert_eq!(
+            headers.get("x-a"),
+            Some(&HeaderValue::from_static("default-a"))
+        );
+        let b: Vec<_> = headers.get_all("x-b").iter().collect();
+        assert_eq!(b, vec![&HeaderValue::from_static("call-b")]);
+    }
+}
@@ -17,46 +17,110 @@ use futures::{Stream, StreamExt};
 use pin_project::pin_project;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 
 /// Describes a type that can be iterated over asyncly when used with [Paginator].
 pub trait PageableResponse {
+    /// The type of the items contained in each page.
+    type PageItem;
+
+    /// Consumes the page and returns its items.
+    fn items(self) -> Vec<Self::PageItem>;
+
+    /// Returns the token to fetch the next page, empty for the last page.
     fn next_page_token(&self) -> String;
 }
 
+/// Converts a page token field into the [String] expected by [Paginator].
+///
+/// Some services define the page tokens as optional fields, an unset token is
+/// equivalent to an empty one.
+#[doc(hidden)]
+pub fn extract_token<T: Into<Option<String>>>(v: T) -> String {
+    v.into().unwrap_or_default()
+}
+
 /// An adapter that converts list RPCs as defined by [AIP-4233](https://google.aip.dev/client-libraries/4233)
 /// into a [futures::Stream] that can be iterated over in an async fashion.
+///
+/// The paginator is [Send], it can be held across `.await` points in tasks
+/// created with `tokio::spawn()`. It also tracks the page tokens, applications
+/// can save them to resume an interrupted iteration with a new paginator.
 #[pin_project]
 pub struct Paginator<T, E> {
     #[pin]
-    stream: Pin<Box<dyn Stream<Item = Result<T, E>>>>,
+    stream: Pin<Box<dyn Stream<Item = Result<T, E>> + Send>>,
+    tokens: Arc<Mutex<PageTokens>>,
+}
+
+// The page tokens are updated by the stream and queried by the application.
+#[derive(Debug)]
+struct PageTokens {
+    current: String,
+    next: Option<String>,
+}
+
+impl PageTokens {
+    fn new(seed_token: String) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self {
+            current: seed_token.clone(),
+            next: Some(seed_token),
+        }))
+    }
+
+    fn current(tokens: &Mutex<Self>) -> String {
+        tokens
+            .lock()
+            .expect("page tokens are never poisoned")
+            .current
+            .clone()
+    }
+
+    fn next(tokens: &Mutex<Self>) -> Option<String> {
+        tokens
+            .lock()
+            .expect("page tokens are never poisoned")
+            .next
+            .clone()
+    }
 }
 
 type ControlFlow = std::ops::ControlFlow<(), String>;
 
 impl<T, E> Paginator<T, E>
 where
-    T: PageableResponse,
+    T: PageableResponse + Send + 'static,
+    E: Send + 'static,
 {
     /// Creates a new [Paginator] given the initial page token and a function
     /// to fetch the next [PageableResponse].
-    pub fn new<F>(seed_token: String, execute: impl Fn(String) -> F + Clone + 'static) -> Self
+    pub fn new<F>(
+        seed_token: String,
+        execute: impl Fn(String) -> F + Clone + Send + 'static,
+    ) -> Self
     where
-        F: Future<Output = Result<T, E>> + 'static,
+        F: Future<Output = Result<T, E>> + Send + 'static,
     {
+        let tokens = PageTokens::new(seed_token.clone());
+        let shared = tokens.clone();
         let stream = unfold(ControlFlow::Continue(seed_token), move |state| {
             let execute = execute.clone();
+            let tokens = shared.clone();
             async move {
                 let token = match state {
                     ControlFlow::Continue(token) => token,
                     ControlFlow::Break(_) => return None,
                 };
-                let resp = match execute(token).await {
+                let resp = match execute(token.clone()).await {
                     Ok(page_resp) => {
                         let tok = page_resp.next_page_token();
-                        let next_state = if tok.is_empty() {
-                            ControlFlow::Break(())
-                        } else {
-                            ControlFlow::Continue(tok)
+                        let next = if tok.is_empty() { None } else { Some(tok) };
+                        let mut guard = tokens.lock().expect("page tokens are never poisoned");
+                        guard.current = token;
+                        guard.next = next.clone();
+                        let next_state = match next {
+                            None => ControlFlow::Break(()),
+                            Some(tok) => ControlFlow::Continue(tok),
                         };
                         Some((Ok(page_resp), next_state))
                     }
@@ -67,13 +131,54 @@ where
         });
         Self {
             stream: Box::pin(stream),
+            tokens,
         }
     }
 
     /// Returns the next mutation of the wrapped stream.
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> futures::stream::Next<'_, Self> {
         StreamExt::next(self)
     }
+
+    /// Returns the token used to fetch the most recent page.
+    ///
+    /// Before the first page is returned this is the seed token. A new
+    /// paginator created with this token starts from the same page, this is
+    /// useful to resume an iteration that failed while processing a page.
+    pub fn current_page_token(&self) -> String {
+        PageTokens::current(&self.tokens)
+    }
+
+    /// Returns the token to fetch the page after the most recent one.
+    ///
+    /// Returns `None` once the last page has been returned. If fetching a page
+    /// fails this is the token of the failed page, so a new paginator created
+    /// with this token retries it.
+    pub fn next_page_token(&self) -> Option<String> {
+        PageTokens::next(&self.tokens)
+    }
+
+    /// Converts the stream of pages into a stream of the items in each page.
+    ///
+    /// Errors fetching a page are returned in place of the page items, and
+    /// terminate the stream.
+    pub fn items(self) -> ItemPaginator<T, E>
+    where
+        T::PageItem: Send + 'static,
+    {
+        let stream = self.stream.flat_map(|page| {
+            let items: Vec<Result<T::PageItem, E>> = match page {
+                Ok(page) => page.items().into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            };
+            futures::stream::iter(items)
+        });
+        ItemPaginator {
+            stream: Box::pin(stream),
+            tokens: self.tokens,
+        }
+    }
 }
 
 impl<T, E> Stream for Paginator<T, E> {
@@ -87,6 +192,72 @@ impl<T, E> Stream for Paginator<T, E> {
     }
 }
 
+/// An adapter that converts a [Paginator] into a [futures::Stream] of the
+/// items in each page.
+#[pin_project]
+pub struct ItemPaginator<T, E>
+where
+    T: PageableResponse,
+{
+    #[pin]
+    stream: Pin<Box<dyn Stream<Item = Result<T::PageItem, E>> + Send>>,
+    tokens: Arc<Mutex<PageTokens>>,
+}
+
+impl<T, E> ItemPaginator<T, E>
+where
+    T: PageableResponse,
+{
+    /// Returns the next item of the wrapped stream.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> futures::stream::Next<'_, Self> {
+        StreamExt::next(self)
+    }
+
+    /// Limits the total number of items returned by the stream.
+    ///
+    /// No more pages are fetched once `v` items are returned. To also limit
+    /// the items fetched in each page, set the page size in the request.
+    pub fn set_max_items(mut self, v: usize) -> Self
+    where
+        T::PageItem: Send + 'static,
+        E: Send + 'static,
+    {
+        self.stream = Box::pin(self.stream.take(v));
+        self
+    }
+
+    /// Returns the token used to fetch the page containing the most recent
+    /// item.
+    ///
+    /// See [Paginator::current_page_token].
+    pub fn current_page_token(&self) -> String {
+        PageTokens::current(&self.tokens)
+    }
+
+    /// Returns the token to fetch the page after the one containing the most
+    /// recent item.
+    ///
+    /// See [Paginator::next_page_token].
+    pub fn next_page_token(&self) -> Option<String> {
+        PageTokens::next(&self.tokens)
+    }
+}
+
+impl<T, E> Stream for ItemPaginator<T, E>
+where
+    T: PageableResponse,
+{
+    type Item = Result<T::PageItem, E>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -109,6 +280,12 @@ mod tests {
     }
 
     impl PageableResponse for TestResponse {
+        type PageItem = PageItem;
+
+        fn items(self) -> Vec<PageItem> {
+            self.items
+        }
+
         fn next_page_token(&self) -> String {
             self.next_page_token.clone()
         }
@@ -127,7 +304,7 @@ mod tests {
         async fn execute(
             data: Arc<Mutex<Vec<TestResponse>>>,
             _: TestRequest,
-        ) -> Result<TestResponse, Box<dyn std::error::Error>> {
+        ) -> Result<TestResponse, Box<dyn std::error::Error + Send + Sync>> {
             // This is where we could run a request with a client
             let mut responses = data.lock().unwrap();
             let resp: TestResponse = responses.remove(0);
@@ -137,7 +314,7 @@ mod tests {
         async fn list_rpc(
             &self,
             req: TestRequest,
-        ) -> Result<TestResponse, Box<dyn std::error::Error>> {
+        ) -> Result<TestResponse, Box<dyn std::error::Error + Send + Sync>> {
             let inner = self.inner.clone();
             Client::execute(inner.data.clone(), req).await
         }
@@ -145,7 +322,7 @@ mod tests {
         fn list_rpc_stream(
             &self,
             req: TestRequest,
-        ) -> Paginator<TestResponse, Box<dyn std::error::Error>> {
+        ) -> Paginator<TestResponse, Box<dyn std::error::Error + Send + Sync>> {
             let client = self.clone();
             let tok = req.page_token.clone();
             let execute = move |token| {
@@ -194,7 +371,7 @@ mod tests {
         };
 
         let mut resps = vec![];
-        let mut stream: Paginator<TestResponse, Box<dyn std::error::Error>> =
+        let mut stream: Paginator<TestResponse, Box<dyn std::error::Error + Send + Sync>> =
             Paginator::new(seed, execute);
         while let Some(resp) = stream.next().await {
             if let Ok(resp) = resp {
@@ -248,7 +425,9 @@ mod tests {
 
     #[tokio::test]
     async fn test_paginator_error() {
-        let execute = |_| async { Err::<TestResponse, Box<dyn std::error::Error>>("err".into()) };
+        let execute = |_| async {
+            Err::<TestResponse, Box<dyn std::error::Error + Send + Sync>>("err".into())
+        };
 
         let mut paginator = Paginator::new(String::new(), execute);
         let mut count = 0;
@@ -265,4 +444,179 @@ mod tests {
         }
         assert_eq!(count, 1);
     }
+
+    #[tokio::test]
+    async fn test_items() {
+        let responses = vec![
+            TestResponse {
+                items: vec![
+                    PageItem {
+                        name: "item1".to_string(),
+                    },
+                    PageItem {
+                        name: "item2".to_string(),
+                    },
+                ],
+                next_page_token: "token1".to_string(),
+            },
+            TestResponse {
+                items: vec![],
+                next_page_token: "token2".to_string(),
+            },
+            TestResponse {
+                items: vec![PageItem {
+                    name: "item3".to_string(),
+                }],
+                next_page_token: "".to_string(),
+            },
+        ];
+
+        let client = Client {
+            inner: Arc::new(InnerClient {
+                data: Arc::new(Mutex::new(responses)),
+            }),
+        };
+        let mut names = vec![];
+        let mut items = client.list_rpc_stream(TestRequest::default()).items();
+        while let Some(item) = items.next().await {
+            names.push(item.unwrap().name);
+        }
+        assert_eq!(names, vec!["item1", "item2", "item3"]);
+    }
+
+    #[tokio::test]
+    async fn test_items_error() {
+        let state = Arc::new(Mutex::new(VecDeque::from(vec![
+            Ok(TestResponse {
+                items: vec![PageItem {
+                    name: "item1".to_string(),
+                }],
+                next_page_token: "token1".to_string(),
+            }),
+            Err("err".to_string()),
+        ])));
+        let execute = move |_| {
+            let resp = state.lock().unwrap().pop_front().unwrap();
+            async move { resp }
+        };
+
+        let mut items = Paginator::new(String::new(), execute).items();
+        assert_eq!(items.next().await.unwrap().unwrap().name, "item1");
+        assert_eq!(items.next().await.unwrap().err(), Some("err".to_string()));
+        assert!(items.next().await.is_none());
+    }
+
+    #[test]
+    fn test_extract_token() {
+        assert_eq!(extract_token("abc".to_string()), "abc");
+        assert_eq!(extract_token(Some("abc".to_string())), "abc");
+        assert_eq!(extract_token(None), "");
+    }
+
+    fn page(names: &[&str], next_page_token: &str) -> TestResponse {
+        TestResponse {
+            items: names
+                .iter()
+                .map(|n| PageItem {
+                    name: n.to_string(),
+                })
+                .collect(),
+            next_page_token: next_page_token.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginator_send() {
+        let client = Client {
+            inner: Arc::new(InnerClient {
+                data: Arc::new(Mutex::new(vec![
+                    page(&["item1", "item2"], "token1"),
+                    page(&["item3"], ""),
+                ])),
+            }),
+        };
+        let mut items = client.list_rpc_stream(TestRequest::default()).items();
+        let names = tokio::spawn(async move {
+            let mut names = vec![];
+            while let Some(item) = items.next().await {
+                names.push(item.unwrap().name);
+            }
+            names
+        })
+        .await
+        .unwrap();
+        assert_eq!(names, vec!["item1", "item2", "item3"]);
+    }
+
+    #[tokio::test]
+    async fn test_page_tokens() {
+        let client = Client {
+            inner: Arc::new(InnerClient {
+                data: Arc::new(Mutex::new(vec![
+                    page(&["item1"], "token2"),
+                    page(&["item2"], ""),
+                ])),
+            }),
+        };
+        let mut stream = client.list_rpc_stream(TestRequest {
+            page_token: "token1".to_string(),
+        });
+        assert_eq!(stream.current_page_token(), "token1");
+        assert_eq!(stream.next_page_token(), Some("token1".to_string()));
+
+        assert!(stream.next().await.is_some());
+        assert_eq!(stream.current_page_token(), "token1");
+        assert_eq!(stream.next_page_token(), Some("token2".to_string()));
+
+        assert!(stream.next().await.is_some());
+        assert_eq!(stream.current_page_token(), "token2");
+        assert_eq!(stream.next_page_token(), None);
+
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_page_tokens_error() {
+        let state = Arc::new(Mutex::new(VecDeque::from(vec![
+            Ok(page(&["item1"], "token2")),
+            Err("err".to_string()),
+        ])));
+        let execute = move |_| {
+            let resp = state.lock().unwrap().pop_front().unwrap();
+            async move { resp }
+        };
+
+        let mut items = Paginator::new("token1".to_string(), execute).items();
+        assert!(items.next().await.unwrap().is_ok());
+        assert_eq!(items.current_page_token(), "token1");
+        assert!(items.next().await.unwrap().is_err());
+        // Resuming from the next page token retries the failed page.
+        assert_eq!(items.next_page_token(), Some("token2".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_max_items() {
+        let requests = Arc::new(Mutex::new(0));
+        let counter = requests.clone();
+        let execute = move |token: String| {
+            *counter.lock().unwrap() += 1;
+            let resp = match token.as_str() {
+                "" => page(&["item1", "item2"], "token1"),
+                "token1" => page(&["item3", "item4"], "token2"),
+                _ => page(&["item5"], ""),
+            };
+            async move { Ok::<_, String>(resp) }
+        };
+
+        let mut items = Paginator::new(String::new(), execute)
+            .items()
+            .set_max_items(3);
+        let mut names = vec![];
+        while let Some(item) = items.next().await {
+            names.push(item.unwrap().name);
+        }
+        assert_eq!(names, vec!["item1", "item2", "item3"]);
+        assert_eq!(*requests.lock().unwrap(), 2);
+        assert_eq!(items.current_page_token(), "token1");
+    }
 }
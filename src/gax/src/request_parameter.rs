@@ -74,7 +74,7 @@ impl RequestParameter for wkt::Duration {
 
 impl RequestParameter for wkt::FieldMask {
     fn format(&self) -> Result {
-        Ok(self.paths.join(","))
+        Ok(serde_json::to_value(self)?.as_str().unwrap().to_string())
     }
 }
 
@@ -114,6 +114,14 @@ mod tests {
         let fm = wkt::FieldMask::default().set_paths(["a", "b"].map(str::to_string).to_vec());
         let f = RequestParameter::format(&fm)?;
         assert_eq!("a,b", f);
+
+        let fm = wkt::FieldMask::default().set_paths(
+            ["display_name", "user.phone_number"]
+                .map(str::to_string)
+                .to_vec(),
+        );
+        let f = RequestParameter::format(&fm)?;
+        assert_eq!("displayName,user.phoneNumber", f);
         Ok(())
     }
 
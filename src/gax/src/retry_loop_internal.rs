@@ -0,0 +1,393 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::backoff_policy::BackoffPolicy;
+use crate::error::rpc::StatusDetails;
+use crate::error::Error;
+use crate::options::RequestOptions;
+use crate::retry_policy::{RetryFlow, RetryPolicy};
+use std::sync::Arc;
+use std::time::Duration;
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Runs `inner` until it succeeds or the retry policy stops the loop.
+///
+/// Each call to `inner` is an attempt. The function returns the result of the
+/// first successful attempt, or the error that stopped the loop. Between
+/// attempts the loop calls `sleep` with the delay requested by the service
+/// (via `RetryInfo`), or if there is none, the delay computed by the backoff
+/// policy. Tests can provide a `sleep` function that does not wait.
+///
+/// The loop also uses the following settings from `options`:
+/// * `idempotent()`: passed to the retry policy with each error, most policies
+///   do not retry non-idempotent requests. Requests are not idempotent unless
+///   stated otherwise.
+/// * `attempt_timeout()`: each attempt that takes longer than this fails with
+///   an I/O error.
+/// * `overall_timeout()`: the loop stops once this time has elapsed. Attempts
+///   are cut short to meet this deadline, and the loop does not sleep past it.
+pub async fn retry_loop<F, Fut, S, SFut, Response>(
+    inner: F,
+    sleep: S,
+    options: &RequestOptions,
+    retry_policy: Arc<dyn RetryPolicy>,
+    backoff_policy: Arc<dyn BackoffPolicy>,
+) -> Result<Response>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<Response>>,
+    S: Fn(Duration) -> SFut,
+    SFut: std::future::Future<Output = ()>,
+{
+    let idempotent = options.idempotent().unwrap_or(false);
+    let deadline = options
+        .overall_timeout()
+        .map(|t| tokio::time::Instant::now() + t);
+    let loop_start = std::time::Instant::now();
+    let mut attempt_count = 0;
+    let mut delay = Duration::ZERO;
+    loop {
+        attempt_count += 1;
+        let remaining = deadline.map(|d| d.saturating_duration_since(tokio::time::Instant::now()));
+        let timeout = match (options.attempt_timeout(), remaining) {
+            (Some(a), Some(r)) => Some(a.min(r)),
+            (a, r) => a.or(r),
+        };
+        let error = match attempt(inner(), timeout).await {
+            Ok(response) => return Ok(response),
+            Err(e) => e,
+        };
+        match retry_policy.on_error(loop_start, attempt_count, idempotent, error) {
+            RetryFlow::Permanent(e) | RetryFlow::Exhausted(e) => return Err(e),
+            RetryFlow::Continue(e) => {
+                delay = server_retry_delay(&e)
+                    .unwrap_or_else(|| backoff_policy.on_failure(loop_start, attempt_count, delay));
+                if deadline.is_some_and(|d| tokio::time::Instant::now() + delay >= d) {
+                    return Err(e);
+                }
+                sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// Runs a single attempt, failing with an I/O error if it exceeds `timeout`.
+async fn attempt<Fut, Response>(inner: Fut, timeout: Option<Duration>) -> Result<Response>
+where
+    Fut: std::future::Future<Output = Result<Response>>,
+{
+    match timeout {
+        None => inner.await,
+        Some(t) => tokio::time::timeout(t, inner)
+            .await
+            .unwrap_or_else(|_| Err(Error::io(format!("attempt timed out after {t:?}")))),
+    }
+}
+
+/// Sleeps for `delay`, the default `sleep` function for [retry_loop].
+pub async fn sleep(delay: Duration) {
+    tokio::time::sleep(delay).await
+}
+
+/// Returns the `RetryInfo.retry_delay` included in the error details, if any.
+fn server_retry_delay(error: &Error) -> Option<Duration> {
+    error.status()?.details.into_iter().find_map(|d| match d {
+        StatusDetails::RetryInfo(info) => info.retry_delay.and_then(|d| Duration::try_from(d).ok()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backoff_policy::{ExponentialBackoffBuilder, Jitter};
+    use crate::error::HttpError;
+    use crate::retry_policy::{LimitedAttemptCount, NeverRetry};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    async fn no_sleep(_: Duration) {}
+
+    fn idempotent() -> RequestOptions {
+        RequestOptions::default().set_idempotency(true)
+    }
+
+    fn backoff() -> Arc<dyn BackoffPolicy> {
+        Arc::new(
+            ExponentialBackoffBuilder::new()
+                .set_initial_delay(Duration::from_secs(1))
+                .set_maximum_delay(Duration::from_secs(10))
+                .set_jitter(Jitter::None)
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn unavailable() -> Error {
+        HttpError::new(503, HashMap::new(), None).into()
+    }
+
+    #[tokio::test]
+    async fn success_after_retries() -> Result<()> {
+        let attempts = Mutex::new(0);
+        let inner = || {
+            let mut count = attempts.lock().unwrap();
+            *count += 1;
+            let result = if *count < 3 {
+                Err(unavailable())
+            } else {
+                Ok(*count)
+            };
+            async move { result }
+        };
+        let got = retry_loop(
+            inner,
+            no_sleep,
+            &idempotent(),
+            Arc::new(LimitedAttemptCount::new(5)),
+            backoff(),
+        )
+        .await?;
+        assert_eq!(got, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn exhausted() {
+        let attempts = Mutex::new(0);
+        let inner = || {
+            *attempts.lock().unwrap() += 1;
+            async { Err::<(), Error>(unavailable()) }
+        };
+        let got = retry_loop(
+            inner,
+            no_sleep,
+            &idempotent(),
+            Arc::new(LimitedAttemptCount::new(3)),
+            backoff(),
+        )
+        .await;
+        assert!(got.is_err(), "{got:?}");
+        assert_eq!(*attempts.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn permanent() {
+        let attempts = Mutex::new(0);
+        let inner = || {
+            *attempts.lock().unwrap() += 1;
+            async { Err::<(), Error>(HttpError::new(404, HashMap::new(), None).into()) }
+        };
+        let got = retry_loop(
+            inner,
+            no_sleep,
+            &idempotent(),
+            Arc::new(LimitedAttemptCount::new(3)),
+            backoff(),
+        )
+        .await;
+        assert!(got.is_err(), "{got:?}");
+        assert_eq!(*attempts.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn never_retry() {
+        let attempts = Mutex::new(0);
+        let inner = || {
+            *attempts.lock().unwrap() += 1;
+            async { Err::<(), Error>(unavailable()) }
+        };
+        let got = retry_loop(
+            inner,
+            no_sleep,
+            &idempotent(),
+            Arc::new(NeverRetry),
+            backoff(),
+        )
+        .await;
+        assert!(got.is_err(), "{got:?}");
+        assert_eq!(*attempts.lock().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn backoff_delays() -> Result<()> {
+        let delays = Mutex::new(Vec::new());
+        let sleep = |d: Duration| {
+            delays.lock().unwrap().push(d);
+            async {}
+        };
+        let attempts = Mutex::new(0);
+        let inner = || {
+            *attempts.lock().unwrap() += 1;
+            async { Err::<(), Error>(unavailable()) }
+        };
+        let got = retry_loop(
+            inner,
+            sleep,
+            &idempotent(),
+            Arc::new(LimitedAttemptCount::new(5)),
+            backoff(),
+        )
+        .await;
+        assert!(got.is_err(), "{got:?}");
+        let want = [1, 2, 4, 8].map(Duration::from_secs).to_vec();
+        assert_eq!(*delays.lock().unwrap(), want);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn server_delay() -> Result<()> {
+        let payload = serde_json::json!({
+            "error": {
+                "code": 503,
+                "message": "try again later",
+                "status": "UNAVAILABLE",
+                "details": [
+                    {
+                        "@type": "type.googleapis.com/google.rpc.RetryInfo",
+                        "retryDelay": "30s"
+                    }
+                ]
+            }
+        });
+        let payload = bytes::Bytes::from(serde_json::to_vec(&payload).unwrap());
+        let delays = Mutex::new(Vec::new());
+        let sleep = |d: Duration| {
+            delays.lock().unwrap().push(d);
+            async {}
+        };
+        let attempts = Mutex::new(0);
+        let inner = || {
+            let mut count = attempts.lock().unwrap();
+            *count += 1;
+            let result = match *count {
+                1 => Err(HttpError::new(503, HashMap::new(), Some(payload.clone())).into()),
+                2 => Err(unavailable()),
+                _ => Ok(*count),
+            };
+            async move { result }
+        };
+        let got = retry_loop(
+            inner,
+            sleep,
+            &idempotent(),
+            Arc::new(LimitedAttemptCount::new(5)),
+            backoff(),
+        )
+        .await?;
+        assert_eq!(got, 3);
+        let want = [Duration::from_secs(30), Duration::from_secs(2)].to_vec();
+        assert_eq!(*delays.lock().unwrap(), want);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tokio_sleep() {
+        let start = tokio::time::Instant::now();
+        sleep(Duration::from_secs(5)).await;
+        assert!(start.elapsed() >= Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn non_idempotent() {
+        let attempts = Mutex::new(0);
+        let inner = || {
+            *attempts.lock().unwrap() += 1;
+            async { Err::<(), Error>(unavailable()) }
+        };
+        let got = retry_loop(
+            inner,
+            no_sleep,
+            &RequestOptions::default(),
+            Arc::new(LimitedAttemptCount::new(3)),
+            backoff(),
+        )
+        .await;
+        assert!(got.is_err(), "{got:?}");
+        assert_eq!(*attempts.lock().unwrap(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn attempt_timeout() -> Result<()> {
+        let attempts = Mutex::new(0);
+        let inner = || {
+            let mut count = attempts.lock().unwrap();
+            *count += 1;
+            let wait = if *count < 3 {
+                Duration::from_secs(10)
+            } else {
+                Duration::ZERO
+            };
+            let count = *count;
+            async move {
+                tokio::time::sleep(wait).await;
+                Ok(count)
+            }
+        };
+        let options = idempotent().set_attempt_timeout(Duration::from_secs(1));
+        let got = retry_loop(
+            inner,
+            sleep,
+            &options,
+            Arc::new(LimitedAttemptCount::new(5)),
+            backoff(),
+        )
+        .await?;
+        assert_eq!(got, 3);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn overall_timeout() {
+        let start = tokio::time::Instant::now();
+        let attempts = Mutex::new(0);
+        let inner = || {
+            *attempts.lock().unwrap() += 1;
+            async { Err::<(), Error>(unavailable()) }
+        };
+        // The backoff delays are 1s, 2s, 4s, 8s. After the fourth attempt the
+        // next delay would exceed the deadline.
+        let options = idempotent().set_overall_timeout(Duration::from_secs(10));
+        let got = retry_loop(
+            inner,
+            sleep,
+            &options,
+            Arc::new(LimitedAttemptCount::new(100)),
+            backoff(),
+        )
+        .await;
+        assert!(got.is_err(), "{got:?}");
+        assert_eq!(*attempts.lock().unwrap(), 4);
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn overall_timeout_cuts_attempt() {
+        let start = tokio::time::Instant::now();
+        let inner = || async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        };
+        let options = idempotent()
+            .set_attempt_timeout(Duration::from_secs(30))
+            .set_overall_timeout(Duration::from_secs(5));
+        let got = retry_loop(inner, sleep, &options, Arc::new(NeverRetry), backoff()).await;
+        let err = got.unwrap_err();
+        assert_eq!(err.kind(), crate::error::ErrorKind::Io);
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_secs(5), "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(6), "{elapsed:?}");
+    }
+}
@@ -0,0 +1,379 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::error::rpc::Code;
+use crate::error::{Error, ErrorKind};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The result of a [RetryPolicy] decision.
+#[derive(Debug)]
+pub enum RetryFlow {
+    /// The error is not retryable, the request must not be attempted again.
+    Permanent(Error),
+    /// The error may be retryable, but the policy has exhausted its budget.
+    Exhausted(Error),
+    /// The error is retryable, the request should be attempted again.
+    Continue(Error),
+}
+
+/// Determines how errors are handled in the retry loop.
+///
+/// Implementations of this trait decide if an error is retryable, and if the
+/// retry loop has exhausted its budget (in time or attempts) to retry the
+/// request.
+pub trait RetryPolicy: Send + Sync + std::fmt::Debug {
+    /// Query the retry policy after an error.
+    ///
+    /// # Parameters
+    /// * `loop_start` - when the retry loop started.
+    /// * `attempt_count` - the number of attempts, including the attempt that
+    ///   just failed.
+    /// * `idempotent` - if `true`, the request can be safely attempted more
+    ///   than once. Policies should not retry non-idempotent requests unless
+    ///   the error guarantees the request was not processed.
+    /// * `error` - the error returned by the last attempt.
+    fn on_error(
+        &self,
+        loop_start: Instant,
+        attempt_count: u32,
+        idempotent: bool,
+        error: Error,
+    ) -> RetryFlow;
+}
+
+/// Extension methods to compose [RetryPolicy] implementations.
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_gax::retry_policy::*;
+/// # use gcp_sdk_gax::error::rpc::Code;
+/// use std::time::Duration;
+/// let policy = RetryOnCodes::new([Code::Unavailable, Code::Aborted])
+///     .with_attempt_limit(5)
+///     .with_time_limit(Duration::from_secs(30));
+/// ```
+pub trait RetryPolicyExt: RetryPolicy + Sized {
+    /// Decorate the policy to stop retrying once `maximum_duration` has
+    /// elapsed since the start of the retry loop.
+    fn with_time_limit(self, maximum_duration: Duration) -> LimitedElapsedTime<Self> {
+        LimitedElapsedTime::custom(self, maximum_duration)
+    }
+
+    /// Decorate the policy to stop retrying after `maximum_attempts`.
+    fn with_attempt_limit(self, maximum_attempts: u32) -> LimitedAttemptCount<Self> {
+        LimitedAttemptCount::custom(self, maximum_attempts)
+    }
+}
+
+impl<T: RetryPolicy> RetryPolicyExt for T {}
+
+/// A helper type to use [RetryPolicy] in client and request options.
+///
+/// Functions that accept a retry policy take `impl Into<RetryPolicyArg>`, so
+/// applications can pass any concrete policy or a shared
+/// `Arc<dyn RetryPolicy>`.
+#[derive(Clone, Debug)]
+pub struct RetryPolicyArg(Arc<dyn RetryPolicy>);
+
+impl<T: RetryPolicy + 'static> From<T> for RetryPolicyArg {
+    fn from(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl From<Arc<dyn RetryPolicy>> for RetryPolicyArg {
+    fn from(value: Arc<dyn RetryPolicy>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<RetryPolicyArg> for Arc<dyn RetryPolicy> {
+    fn from(value: RetryPolicyArg) -> Self {
+        value.0
+    }
+}
+
+/// A retry policy that never retries.
+///
+/// This is the default policy for generated clients.
+#[derive(Clone, Debug, Default)]
+pub struct NeverRetry;
+
+impl RetryPolicy for NeverRetry {
+    fn on_error(
+        &self,
+        _loop_start: Instant,
+        _attempt_count: u32,
+        _idempotent: bool,
+        error: Error,
+    ) -> RetryFlow {
+        RetryFlow::Exhausted(error)
+    }
+}
+
+/// A retry policy that retries errors with one of the given status codes.
+///
+/// I/O errors, such as a broken connection, are treated as
+/// [Code::Unavailable]. All other errors are permanent. Errors from
+/// non-idempotent requests are always permanent, as retrying them may repeat
+/// their side effects.
+///
+/// This policy does not limit the number of attempts or the time spent
+/// retrying. Use [RetryPolicyExt] to add such limits.
+#[derive(Clone, Debug)]
+pub struct RetryOnCodes {
+    codes: HashSet<Code>,
+}
+
+impl RetryOnCodes {
+    /// Creates a policy that retries errors with any of the given codes.
+    pub fn new<I: IntoIterator<Item = Code>>(codes: I) -> Self {
+        Self {
+            codes: codes.into_iter().collect(),
+        }
+    }
+}
+
+impl Default for RetryOnCodes {
+    /// Retries [Code::Unavailable], the only code that is always safe to
+    /// retry according to [AIP-194](https://google.aip.dev/194).
+    fn default() -> Self {
+        Self::new([Code::Unavailable])
+    }
+}
+
+impl RetryPolicy for RetryOnCodes {
+    fn on_error(
+        &self,
+        _loop_start: Instant,
+        _attempt_count: u32,
+        idempotent: bool,
+        error: Error,
+    ) -> RetryFlow {
+        if !idempotent {
+            return RetryFlow::Permanent(error);
+        }
+        match error_code(&error) {
+            Some(code) if self.codes.contains(&code) => RetryFlow::Continue(error),
+            _ => RetryFlow::Permanent(error),
+        }
+    }
+}
+
+/// A retry policy decorator that limits the total time in the retry loop.
+#[derive(Clone, Debug)]
+pub struct LimitedElapsedTime<P = RetryOnCodes>
+where
+    P: RetryPolicy,
+{
+    inner: P,
+    maximum_duration: Duration,
+}
+
+impl LimitedElapsedTime {
+    /// Creates a policy that retries [RetryOnCodes::default()] errors for up
+    /// to `maximum_duration`.
+    pub fn new(maximum_duration: Duration) -> Self {
+        Self::custom(RetryOnCodes::default(), maximum_duration)
+    }
+}
+
+impl<P: RetryPolicy> LimitedElapsedTime<P> {
+    /// Decorates `inner` to stop retrying after `maximum_duration`.
+    pub fn custom(inner: P, maximum_duration: Duration) -> Self {
+        Self {
+            inner,
+            maximum_duration,
+        }
+    }
+}
+
+impl<P: RetryPolicy> RetryPolicy for LimitedElapsedTime<P> {
+    fn on_error(
+        &self,
+        loop_start: Instant,
+        attempt_count: u32,
+        idempotent: bool,
+        error: Error,
+    ) -> RetryFlow {
+        match self
+            .inner
+            .on_error(loop_start, attempt_count, idempotent, error)
+        {
+            RetryFlow::Continue(e) if loop_start.elapsed() >= self.maximum_duration => {
+                RetryFlow::Exhausted(e)
+            }
+            flow => flow,
+        }
+    }
+}
+
+/// A retry policy decorator that limits the number of attempts.
+#[derive(Clone, Debug)]
+pub struct LimitedAttemptCount<P = RetryOnCodes>
+where
+    P: RetryPolicy,
+{
+    inner: P,
+    maximum_attempts: u32,
+}
+
+impl LimitedAttemptCount {
+    /// Creates a policy that retries [RetryOnCodes::default()] errors for up
+    /// to `maximum_attempts`.
+    pub fn new(maximum_attempts: u32) -> Self {
+        Self::custom(RetryOnCodes::default(), maximum_attempts)
+    }
+}
+
+impl<P: RetryPolicy> LimitedAttemptCount<P> {
+    /// Decorates `inner` to stop retrying after `maximum_attempts`.
+    pub fn custom(inner: P, maximum_attempts: u32) -> Self {
+        Self {
+            inner,
+            maximum_attempts,
+        }
+    }
+}
+
+impl<P: RetryPolicy> RetryPolicy for LimitedAttemptCount<P> {
+    fn on_error(
+        &self,
+        loop_start: Instant,
+        attempt_count: u32,
+        idempotent: bool,
+        error: Error,
+    ) -> RetryFlow {
+        match self
+            .inner
+            .on_error(loop_start, attempt_count, idempotent, error)
+        {
+            RetryFlow::Continue(e) if attempt_count >= self.maximum_attempts => {
+                RetryFlow::Exhausted(e)
+            }
+            flow => flow,
+        }
+    }
+}
+
+/// Returns the [Code] associated with an error, if any.
+fn error_code(error: &Error) -> Option<Code> {
+    if error.kind() == ErrorKind::Io {
+        return Some(Code::Unavailable);
+    }
+    error.code()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::HttpError;
+    use std::collections::HashMap;
+
+    fn http_error(status_code: u16) -> Error {
+        HttpError::new(status_code, HashMap::new(), None).into()
+    }
+
+    #[test]
+    fn never_retry() {
+        let p = NeverRetry;
+        let flow = p.on_error(Instant::now(), 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Exhausted(_)), "{flow:?}");
+    }
+
+    #[test]
+    fn retry_on_codes() {
+        let p = RetryOnCodes::default();
+        let flow = p.on_error(Instant::now(), 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 1, true, http_error(404));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 1, true, Error::io("broken pipe"));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 1, true, Error::authentication("bad"));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+
+        let p = RetryOnCodes::new([Code::ResourceExhausted, Code::Aborted]);
+        let flow = p.on_error(Instant::now(), 1, true, http_error(429));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 1, true, http_error(409));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+    }
+
+    #[test]
+    fn limited_attempt_count() {
+        let p = LimitedAttemptCount::new(3);
+        let flow = p.on_error(Instant::now(), 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 2, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 3, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Exhausted(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 1, true, http_error(400));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+    }
+
+    #[test]
+    fn limited_elapsed_time() {
+        let p = LimitedElapsedTime::new(Duration::from_secs(60));
+        let flow = p.on_error(Instant::now(), 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let start = Instant::now() - Duration::from_secs(120);
+        let flow = p.on_error(start, 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Exhausted(_)), "{flow:?}");
+        let flow = p.on_error(start, 1, true, http_error(403));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+    }
+
+    #[test]
+    fn compose() {
+        let p = RetryOnCodes::new([Code::Unavailable])
+            .with_attempt_limit(2)
+            .with_time_limit(Duration::from_secs(60));
+        let flow = p.on_error(Instant::now(), 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Continue(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 2, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Exhausted(_)), "{flow:?}");
+        let start = Instant::now() - Duration::from_secs(120);
+        let flow = p.on_error(start, 1, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Exhausted(_)), "{flow:?}");
+    }
+
+    #[test]
+    fn policy_arg() {
+        let arg: RetryPolicyArg = LimitedAttemptCount::new(3).into();
+        let policy: Arc<dyn RetryPolicy> = arg.into();
+        let arg: RetryPolicyArg = policy.clone().into();
+        let flow =
+            Arc::<dyn RetryPolicy>::from(arg).on_error(Instant::now(), 3, true, http_error(503));
+        assert!(matches!(flow, RetryFlow::Exhausted(_)), "{flow:?}");
+    }
+
+    #[test]
+    fn non_idempotent() {
+        let p = RetryOnCodes::default();
+        let flow = p.on_error(Instant::now(), 1, false, http_error(503));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+        let flow = p.on_error(Instant::now(), 1, false, Error::io("broken pipe"));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+
+        let p = LimitedAttemptCount::new(3).with_time_limit(Duration::from_secs(60));
+        let flow = p.on_error(Instant::now(), 1, false, http_error(503));
+        assert!(matches!(flow, RetryFlow::Permanent(_)), "{flow:?}");
+    }
+}
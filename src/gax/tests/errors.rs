@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use gcp_sdk_gax::error::rpc::Status;
+use gcp_sdk_gax::error::rpc::{Code, Status, StatusDetails};
 use gcp_sdk_gax::error::Error;
 use gcp_sdk_gax::error::HttpError;
 use std::collections::HashMap;
@@ -130,3 +130,80 @@ fn http_error_to_status() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn http_error_without_payload() {
+    let http_err = HttpError::new(503, HashMap::new(), None);
+    let status: Result<Status, Error> = http_err.clone().try_into();
+    assert!(status.is_err(), "{status:?}");
+    assert_eq!(http_err.status(), None);
+    assert_eq!(http_err.code(), Code::Unavailable);
+
+    let error = Error::from(http_err);
+    assert_eq!(error.code(), Some(Code::Unavailable));
+    assert_eq!(error.status(), None);
+}
+
+#[test]
+fn http_error_non_json_payload() {
+    let http_err = HttpError::new(
+        502,
+        HashMap::from_iter([("content-type".to_string(), "text/html".to_string())]),
+        Some("<html><title>Bad Gateway</title></html>".into()),
+    );
+    assert_eq!(http_err.status(), None);
+    assert_eq!(http_err.code(), Code::Internal);
+
+    let http_err = HttpError::new(404, HashMap::new(), Some("".into()));
+    assert_eq!(http_err.status(), None);
+    assert_eq!(http_err.code(), Code::NotFound);
+}
+
+#[test]
+fn http_error_code_from_status_name() -> Result<(), Box<dyn std::error::Error>> {
+    // Services return 400 for several codes, the name is more precise.
+    let json = serde_json::json!({"error": {
+        "code": 400,
+        "message": "precondition failed",
+        "status": "FAILED_PRECONDITION",
+        "details": [
+            {
+                "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+                "reason": "reason",
+                "domain": "domain",
+                "metadata": {}
+            }
+        ]
+    }});
+    let error = Error::from(HttpError::new(
+        400,
+        HashMap::new(),
+        Some(json.to_string().into()),
+    ));
+    assert_eq!(error.code(), Some(Code::FailedPrecondition));
+    let status = error.status().ok_or("missing status")?;
+    assert_eq!(status.code, 400);
+    assert_eq!(status.message, "precondition failed");
+    assert!(
+        matches!(status.details.as_slice(), [StatusDetails::ErrorInfo(_)]),
+        "{status:?}"
+    );
+
+    // Unknown names fall back to the HTTP status code.
+    let json = serde_json::json!({"error": {"code": 403, "message": "m", "status": "NEW_CODE"}});
+    let error = Error::from(HttpError::new(
+        403,
+        HashMap::new(),
+        Some(json.to_string().into()),
+    ));
+    assert_eq!(error.code(), Some(Code::PermissionDenied));
+    Ok(())
+}
+
+#[test]
+fn error_code_non_rpc() {
+    let error = Error::io("broken pipe");
+    assert_eq!(error.code(), None);
+    assert_eq!(error.status(), None);
+    assert!(error.http_error().is_none());
+}
@@ -125,6 +125,26 @@ fn with_fieldmask() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn with_fieldmask_as_value() -> Result<()> {
+    // The generated code converts message fields to `serde_json::Value` before
+    // adding them as query parameters.
+    let mask = Some(
+        wkt::FieldMask::default()
+            .set_paths(["labels", "version_aliases"].map(str::to_string).to_vec()),
+    );
+    let client = reqwest::Client::builder().build()?;
+    let builder = client.get("https://test.googleapis.com/v1/unused");
+    let builder =
+        gcp_sdk_gax::query_parameter::add(builder, "updateMask", &serde_json::to_value(&mask)?)?;
+
+    let r = builder.build()?;
+    // %2C is the URL-safe encoding for comma (`,`)
+    assert_eq!(Some("updateMask=labels%2CversionAliases"), r.url().query());
+
+    Ok(())
+}
+
 #[test]
 fn with_duration() -> Result<()> {
     // Create a basic request.
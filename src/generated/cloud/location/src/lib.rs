@@ -19,6 +19,7 @@ pub mod model;
 
 use gax::error::Error;
 use google_cloud_auth::{Credential, CredentialConfig};
+use std::sync::Arc;
 
 pub mod traits;
 pub(crate) mod transport;
@@ -33,6 +34,11 @@ pub struct ConfigBuilder {
     pub(crate) endpoint: Option<String>,
     pub(crate) client: Option<reqwest::Client>,
     pub(crate) cred: Option<Credential>,
+    pub(crate) request_options: gax::options::RequestOptions,
+    pub(crate) proxy: Option<reqwest::Proxy>,
+    pub(crate) root_certificates: Vec<reqwest::Certificate>,
+    pub(crate) tls_built_in_root_certs: Option<bool>,
+    pub(crate) user_agent_suffix: Option<String>,
 }
 
 impl ConfigBuilder {
@@ -47,8 +53,118 @@ impl ConfigBuilder {
         self
     }
 
-    pub(crate) fn default_client() -> reqwest::Client {
-        reqwest::Client::builder().build().unwrap()
+    /// Sets the credential used to authenticate all the requests from this
+    /// client.
+    ///
+    /// By default the client uses [Application Default Credentials], which
+    /// may require network requests to discover. Use
+    /// [Credential::from_access_token] or [Credential::from_api_key] for a
+    /// token or API key obtained elsewhere.
+    ///
+    /// [Application Default Credentials]: https://google.aip.dev/auth/4110
+    pub fn set_credential(mut self, v: Credential) -> Self {
+        self.cred = Some(v);
+        self
+    }
+
+    /// Sets the HTTP client used by this client.
+    ///
+    /// Applications can share one [reqwest::Client], and its connection pool,
+    /// across all their clients. When set, the proxy and TLS settings in this
+    /// builder are ignored, configure them in the [reqwest::Client] instead.
+    pub fn set_http_client(mut self, v: reqwest::Client) -> Self {
+        self.client = Some(v);
+        self
+    }
+
+    /// Sets a proxy for the HTTP client created by this builder.
+    pub fn set_proxy(mut self, v: reqwest::Proxy) -> Self {
+        self.proxy = Some(v);
+        self
+    }
+
+    /// Adds a trusted root certificate to the HTTP client created by this
+    /// builder.
+    pub fn add_root_certificate(mut self, v: reqwest::Certificate) -> Self {
+        self.root_certificates.push(v);
+        self
+    }
+
+    /// Controls the use of the built-in root certificates in the HTTP client
+    /// created by this builder. They are used by default.
+    pub fn set_tls_built_in_root_certs(mut self, v: bool) -> Self {
+        self.tls_built_in_root_certs = Some(v);
+        self
+    }
+
+    /// Appends `v` to the `User-Agent` header sent with each request.
+    pub fn set_user_agent_suffix<T: Into<String>>(mut self, v: T) -> Self {
+        self.user_agent_suffix = Some(v.into());
+        self
+    }
+
+    /// Sets the retry policy used by all the requests from this client.
+    ///
+    /// By default the client does not retry any request. The policy can be
+    /// overridden for a single request using
+    /// [RequestOptions](gax::options::RequestOptions).
+    pub fn set_retry_policy<V: Into<gax::retry_policy::RetryPolicyArg>>(mut self, v: V) -> Self {
+        self.request_options = self.request_options.set_retry_policy(v);
+        self
+    }
+
+    /// Sets the backoff policy used by all the requests from this client.
+    ///
+    /// The backoff policy determines the delay between attempts, unless the
+    /// service requests a specific delay in its error details. The policy can
+    /// be overridden for a single request using
+    /// [RequestOptions](gax::options::RequestOptions).
+    pub fn set_backoff_policy<V: Into<gax::backoff_policy::BackoffPolicyArg>>(
+        mut self,
+        v: V,
+    ) -> Self {
+        self.request_options = self.request_options.set_backoff_policy(v);
+        self
+    }
+
+    /// Sets the default options for all the requests from this client.
+    ///
+    /// The options passed to each request are merged over these defaults, see
+    /// [RequestOptions::merge](gax::options::RequestOptions::merge). Note that
+    /// this replaces any retry or backoff policy set previously.
+    pub fn set_request_options(mut self, v: gax::options::RequestOptions) -> Self {
+        self.request_options = v;
+        self
+    }
+
+    pub(crate) fn build_client(&mut self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder();
+        if let Some(proxy) = self.proxy.take() {
+            builder = builder.proxy(proxy);
+        }
+        for cert in std::mem::take(&mut self.root_certificates) {
+            builder = builder.add_root_certificate(cert);
+        }
+        if let Some(v) = self.tls_built_in_root_certs {
+            builder = builder.tls_built_in_root_certs(v);
+        }
+        builder.build().map_err(Error::io)
+    }
+
+    pub(crate) fn user_agent(&self) -> String {
+        let ua = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+        match &self.user_agent_suffix {
+            Some(suffix) => format!("{ua} {suffix}"),
+            None => ua.to_string(),
+        }
+    }
+
+    pub(crate) fn default_retry_policy() -> Arc<dyn gax::retry_policy::RetryPolicy> {
+        Arc::new(gax::retry_policy::NeverRetry)
+    }
+
+    pub(crate) fn default_backoff_policy() -> Arc<dyn gax::backoff_policy::BackoffPolicy> {
+        Arc::new(gax::backoff_policy::ExponentialBackoff::default())
     }
 
     pub(crate) async fn default_credential() -> Result<Credential> {
@@ -59,6 +59,12 @@ impl ListLocationsRequest {
     }
 }
 
+impl wkt::Message for ListLocationsRequest {
+    fn typename() -> &'static str {
+        "google.cloud.location.ListLocationsRequest"
+    }
+}
+
 /// The response message for [Locations.ListLocations][google.cloud.location.Locations.ListLocations].
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -86,6 +92,24 @@ impl ListLocationsResponse {
     }
 }
 
+impl wkt::Message for ListLocationsResponse {
+    fn typename() -> &'static str {
+        "google.cloud.location.ListLocationsResponse"
+    }
+}
+
+impl gax::paginator::PageableResponse for ListLocationsResponse {
+    type PageItem = crate::model::Location;
+
+    fn items(self) -> Vec<Self::PageItem> {
+        self.locations
+    }
+
+    fn next_page_token(&self) -> String {
+        gax::paginator::extract_token(self.next_page_token.clone())
+    }
+}
+
 /// The request message for [Locations.GetLocation][google.cloud.location.Locations.GetLocation].
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -104,6 +128,12 @@ impl GetLocationRequest {
     }
 }
 
+impl wkt::Message for GetLocationRequest {
+    fn typename() -> &'static str {
+        "google.cloud.location.GetLocationRequest"
+    }
+}
+
 /// A resource that represents Google Cloud Platform location.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -165,3 +195,9 @@ impl Location {
         self
     }
 }
+
+impl wkt::Message for Location {
+    fn typename() -> &'static str {
+        "google.cloud.location.Location"
+    }
+}
@@ -32,6 +32,7 @@ pub trait Locations: Send + Sync {
     fn list_locations(
         &self,
         _req: crate::model::ListLocationsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListLocationsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListLocationsResponse>>(Err(Error::other(
@@ -43,6 +44,7 @@ pub trait Locations: Send + Sync {
     fn get_location(
         &self,
         _req: crate::model::GetLocationRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Location>> + Send {
         std::future::ready::<crate::Result<crate::model::Location>>(Err(Error::other(
             "unimplemented",
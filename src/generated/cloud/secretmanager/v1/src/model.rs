@@ -219,6 +219,12 @@ impl Secret {
     }
 }
 
+impl wkt::Message for Secret {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Secret"
+    }
+}
+
 /// Defines additional types related to Secret
 pub mod secret {
 
@@ -372,6 +378,12 @@ impl SecretVersion {
     }
 }
 
+impl wkt::Message for SecretVersion {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.SecretVersion"
+    }
+}
+
 /// Defines additional types related to SecretVersion
 pub mod secret_version {
 
@@ -440,6 +452,12 @@ impl Replication {
     }
 }
 
+impl wkt::Message for Replication {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Replication"
+    }
+}
+
 /// Defines additional types related to Replication
 pub mod replication {
 
@@ -476,6 +494,12 @@ pub mod replication {
         }
     }
 
+    impl wkt::Message for Automatic {
+        fn typename() -> &'static str {
+            "google.cloud.secretmanager.v1.Replication.Automatic"
+        }
+    }
+
     /// A replication policy that replicates the
     /// [Secret][google.cloud.secretmanager.v1.Secret] payload into the locations
     /// specified in [Secret.replication.user_managed.replicas][]
@@ -502,6 +526,12 @@ pub mod replication {
         }
     }
 
+    impl wkt::Message for UserManaged {
+        fn typename() -> &'static str {
+            "google.cloud.secretmanager.v1.Replication.UserManaged"
+        }
+    }
+
     /// Defines additional types related to UserManaged
     pub mod user_managed {
 
@@ -546,6 +576,12 @@ pub mod replication {
                 self
             }
         }
+
+        impl wkt::Message for Replica {
+            fn typename() -> &'static str {
+                "google.cloud.secretmanager.v1.Replication.UserManaged.Replica"
+            }
+        }
     }
 
     /// The replication policy for this secret.
@@ -593,6 +629,12 @@ impl CustomerManagedEncryption {
     }
 }
 
+impl wkt::Message for CustomerManagedEncryption {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.CustomerManagedEncryption"
+    }
+}
+
 /// The replication status of a
 /// [SecretVersion][google.cloud.secretmanager.v1.SecretVersion].
 #[serde_with::serde_as]
@@ -619,6 +661,12 @@ impl ReplicationStatus {
     }
 }
 
+impl wkt::Message for ReplicationStatus {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ReplicationStatus"
+    }
+}
+
 /// Defines additional types related to ReplicationStatus
 pub mod replication_status {
 
@@ -652,6 +700,12 @@ pub mod replication_status {
         }
     }
 
+    impl wkt::Message for AutomaticStatus {
+        fn typename() -> &'static str {
+            "google.cloud.secretmanager.v1.ReplicationStatus.AutomaticStatus"
+        }
+    }
+
     /// The replication status of a
     /// [SecretVersion][google.cloud.secretmanager.v1.SecretVersion] using
     /// user-managed replication.
@@ -681,6 +735,12 @@ pub mod replication_status {
         }
     }
 
+    impl wkt::Message for UserManagedStatus {
+        fn typename() -> &'static str {
+            "google.cloud.secretmanager.v1.ReplicationStatus.UserManagedStatus"
+        }
+    }
+
     /// Defines additional types related to UserManagedStatus
     pub mod user_managed_status {
 
@@ -719,6 +779,12 @@ pub mod replication_status {
                 self
             }
         }
+
+        impl wkt::Message for ReplicaStatus {
+            fn typename() -> &'static str {
+                "google.cloud.secretmanager.v1.ReplicationStatus.UserManagedStatus.ReplicaStatus"
+            }
+        }
     }
 
     /// The replication status of the
@@ -766,6 +832,12 @@ impl CustomerManagedEncryptionStatus {
     }
 }
 
+impl wkt::Message for CustomerManagedEncryptionStatus {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.CustomerManagedEncryptionStatus"
+    }
+}
+
 /// A Pub/Sub topic which Secret Manager will publish to when control plane
 /// events occur on this secret.
 #[serde_with::serde_as]
@@ -789,6 +861,12 @@ impl Topic {
     }
 }
 
+impl wkt::Message for Topic {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Topic"
+    }
+}
+
 /// The rotation time and period for a
 /// [Secret][google.cloud.secretmanager.v1.Secret]. At next_rotation_time, Secret
 /// Manager will send a Pub/Sub notification to the topics configured on the
@@ -838,6 +916,12 @@ impl Rotation {
     }
 }
 
+impl wkt::Message for Rotation {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Rotation"
+    }
+}
+
 /// A secret payload resource in the Secret Manager API. This contains the
 /// sensitive secret payload that is associated with a
 /// [SecretVersion][google.cloud.secretmanager.v1.SecretVersion].
@@ -885,6 +969,12 @@ impl SecretPayload {
     }
 }
 
+impl wkt::Message for SecretPayload {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.SecretPayload"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.ListSecrets][google.cloud.secretmanager.v1.SecretManagerService.ListSecrets].
 #[serde_with::serde_as]
@@ -940,6 +1030,12 @@ impl ListSecretsRequest {
     }
 }
 
+impl wkt::Message for ListSecretsRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretsRequest"
+    }
+}
+
 /// Response message for
 /// [SecretManagerService.ListSecrets][google.cloud.secretmanager.v1.SecretManagerService.ListSecrets].
 #[serde_with::serde_as]
@@ -983,6 +1079,24 @@ impl ListSecretsResponse {
     }
 }
 
+impl wkt::Message for ListSecretsResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretsResponse"
+    }
+}
+
+impl gax::paginator::PageableResponse for ListSecretsResponse {
+    type PageItem = crate::model::Secret;
+
+    fn items(self) -> Vec<Self::PageItem> {
+        self.secrets
+    }
+
+    fn next_page_token(&self) -> String {
+        gax::paginator::extract_token(self.next_page_token.clone())
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.CreateSecret][google.cloud.secretmanager.v1.SecretManagerService.CreateSecret].
 #[serde_with::serde_as]
@@ -1027,6 +1141,12 @@ impl CreateSecretRequest {
     }
 }
 
+impl wkt::Message for CreateSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.CreateSecretRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.AddSecretVersion][google.cloud.secretmanager.v1.SecretManagerService.AddSecretVersion].
 #[serde_with::serde_as]
@@ -1059,6 +1179,12 @@ impl AddSecretVersionRequest {
     }
 }
 
+impl wkt::Message for AddSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AddSecretVersionRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.GetSecret][google.cloud.secretmanager.v1.SecretManagerService.GetSecret].
 #[serde_with::serde_as]
@@ -1080,6 +1206,12 @@ impl GetSecretRequest {
     }
 }
 
+impl wkt::Message for GetSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetSecretRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.ListSecretVersions][google.cloud.secretmanager.v1.SecretManagerService.ListSecretVersions].
 #[serde_with::serde_as]
@@ -1136,6 +1268,12 @@ impl ListSecretVersionsRequest {
     }
 }
 
+impl wkt::Message for ListSecretVersionsRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretVersionsRequest"
+    }
+}
+
 /// Response message for
 /// [SecretManagerService.ListSecretVersions][google.cloud.secretmanager.v1.SecretManagerService.ListSecretVersions].
 #[serde_with::serde_as]
@@ -1180,6 +1318,24 @@ impl ListSecretVersionsResponse {
     }
 }
 
+impl wkt::Message for ListSecretVersionsResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretVersionsResponse"
+    }
+}
+
+impl gax::paginator::PageableResponse for ListSecretVersionsResponse {
+    type PageItem = crate::model::SecretVersion;
+
+    fn items(self) -> Vec<Self::PageItem> {
+        self.versions
+    }
+
+    fn next_page_token(&self) -> String {
+        gax::paginator::extract_token(self.next_page_token.clone())
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.GetSecretVersion][google.cloud.secretmanager.v1.SecretManagerService.GetSecretVersion].
 #[serde_with::serde_as]
@@ -1207,6 +1363,12 @@ impl GetSecretVersionRequest {
     }
 }
 
+impl wkt::Message for GetSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetSecretVersionRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.UpdateSecret][google.cloud.secretmanager.v1.SecretManagerService.UpdateSecret].
 #[serde_with::serde_as]
@@ -1236,6 +1398,12 @@ impl UpdateSecretRequest {
     }
 }
 
+impl wkt::Message for UpdateSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.UpdateSecretRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.AccessSecretVersion][google.cloud.secretmanager.v1.SecretManagerService.AccessSecretVersion].
 #[serde_with::serde_as]
@@ -1263,6 +1431,12 @@ impl AccessSecretVersionRequest {
     }
 }
 
+impl wkt::Message for AccessSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AccessSecretVersionRequest"
+    }
+}
+
 /// Response message for
 /// [SecretManagerService.AccessSecretVersion][google.cloud.secretmanager.v1.SecretManagerService.AccessSecretVersion].
 #[serde_with::serde_as]
@@ -1294,6 +1468,12 @@ impl AccessSecretVersionResponse {
     }
 }
 
+impl wkt::Message for AccessSecretVersionResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AccessSecretVersionResponse"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.DeleteSecret][google.cloud.secretmanager.v1.SecretManagerService.DeleteSecret].
 #[serde_with::serde_as]
@@ -1326,6 +1506,12 @@ impl DeleteSecretRequest {
     }
 }
 
+impl wkt::Message for DeleteSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.DeleteSecretRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.DisableSecretVersion][google.cloud.secretmanager.v1.SecretManagerService.DisableSecretVersion].
 #[serde_with::serde_as]
@@ -1360,6 +1546,12 @@ impl DisableSecretVersionRequest {
     }
 }
 
+impl wkt::Message for DisableSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.DisableSecretVersionRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.EnableSecretVersion][google.cloud.secretmanager.v1.SecretManagerService.EnableSecretVersion].
 #[serde_with::serde_as]
@@ -1394,6 +1586,12 @@ impl EnableSecretVersionRequest {
     }
 }
 
+impl wkt::Message for EnableSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.EnableSecretVersionRequest"
+    }
+}
+
 /// Request message for
 /// [SecretManagerService.DestroySecretVersion][google.cloud.secretmanager.v1.SecretManagerService.DestroySecretVersion].
 #[serde_with::serde_as]
@@ -1427,3 +1625,9 @@ impl DestroySecretVersionRequest {
         self
     }
 }
+
+impl wkt::Message for DestroySecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.DestroySecretVersionRequest"
+    }
+}
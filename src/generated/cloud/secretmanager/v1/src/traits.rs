@@ -36,6 +36,7 @@ pub trait SecretManagerService: Send + Sync {
     fn list_secrets(
         &self,
         _req: crate::model::ListSecretsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListSecretsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListSecretsResponse>>(Err(Error::other(
@@ -48,6 +49,7 @@ pub trait SecretManagerService: Send + Sync {
     fn create_secret(
         &self,
         _req: crate::model::CreateSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -60,6 +62,7 @@ pub trait SecretManagerService: Send + Sync {
     fn add_secret_version(
         &self,
         _req: crate::model::AddSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -70,6 +73,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_secret(
         &self,
         _req: crate::model::GetSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -81,6 +85,7 @@ pub trait SecretManagerService: Send + Sync {
     fn update_secret(
         &self,
         _req: crate::model::UpdateSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -91,6 +96,7 @@ pub trait SecretManagerService: Send + Sync {
     fn delete_secret(
         &self,
         _req: crate::model::DeleteSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<wkt::Empty>> + Send {
         std::future::ready::<crate::Result<wkt::Empty>>(Err(Error::other("unimplemented")))
     }
@@ -100,6 +106,7 @@ pub trait SecretManagerService: Send + Sync {
     fn list_secret_versions(
         &self,
         _req: crate::model::ListSecretVersionsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListSecretVersionsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListSecretVersionsResponse>>(Err(
@@ -115,6 +122,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_secret_version(
         &self,
         _req: crate::model::GetSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -129,6 +137,7 @@ pub trait SecretManagerService: Send + Sync {
     fn access_secret_version(
         &self,
         _req: crate::model::AccessSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::AccessSecretVersionResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::AccessSecretVersionResponse>>(Err(
@@ -144,6 +153,7 @@ pub trait SecretManagerService: Send + Sync {
     fn disable_secret_version(
         &self,
         _req: crate::model::DisableSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -158,6 +168,7 @@ pub trait SecretManagerService: Send + Sync {
     fn enable_secret_version(
         &self,
         _req: crate::model::EnableSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -173,6 +184,7 @@ pub trait SecretManagerService: Send + Sync {
     fn destroy_secret_version(
         &self,
         _req: crate::model::DestroySecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -189,6 +201,7 @@ pub trait SecretManagerService: Send + Sync {
     fn set_iam_policy(
         &self,
         _req: iam_v1::model::SetIamPolicyRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<iam_v1::model::Policy>> + Send {
         std::future::ready::<crate::Result<iam_v1::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -200,6 +213,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_iam_policy(
         &self,
         _req: iam_v1::model::GetIamPolicyRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<iam_v1::model::Policy>> + Send {
         std::future::ready::<crate::Result<iam_v1::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -216,6 +230,7 @@ pub trait SecretManagerService: Send + Sync {
     fn test_iam_permissions(
         &self,
         _req: iam_v1::model::TestIamPermissionsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<iam_v1::model::TestIamPermissionsResponse>> + Send
     {
         std::future::ready::<crate::Result<iam_v1::model::TestIamPermissionsResponse>>(Err(
@@ -238,6 +253,7 @@ pub trait Locations: Send + Sync {
     fn list_locations(
         &self,
         _req: location::model::ListLocationsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<location::model::ListLocationsResponse>> + Send
     {
         std::future::ready::<crate::Result<location::model::ListLocationsResponse>>(Err(
@@ -249,6 +265,7 @@ pub trait Locations: Send + Sync {
     fn get_location(
         &self,
         _req: location::model::GetLocationRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<location::model::Location>> + Send {
         std::future::ready::<crate::Result<location::model::Location>>(Err(Error::other(
             "unimplemented",
@@ -23,6 +23,8 @@ struct InnerClient {
     http_client: reqwest::Client,
     cred: Credential,
     endpoint: String,
+    user_agent: String,
+    options: gax::options::RequestOptions,
 }
 
 #[derive(serde::Serialize)]
@@ -52,41 +54,136 @@ impl SecretManagerService {
         Self::new_with_config(crate::ConfigBuilder::default()).await
     }
 
-    pub async fn new_with_config(conf: crate::ConfigBuilder) -> Result<Self> {
+    pub async fn new_with_config(mut conf: crate::ConfigBuilder) -> Result<Self> {
+        let http_client = match conf.client.take() {
+            Some(client) => client,
+            None => conf.build_client()?,
+        };
+        let cred = match conf.cred.take() {
+            Some(cred) => cred,
+            None => crate::ConfigBuilder::default_credential().await?,
+        };
         let inner = InnerClient {
-            http_client: conf
-                .client
-                .unwrap_or(crate::ConfigBuilder::default_client()),
-            cred: conf
-                .cred
-                .unwrap_or(crate::ConfigBuilder::default_credential().await?),
+            http_client,
+            cred,
+            user_agent: conf.user_agent(),
             endpoint: conf.endpoint.unwrap_or(crate::DEFAULT_HOST.to_string()),
+            options: conf.request_options,
         };
         Ok(Self {
             inner: Arc::new(inner),
         })
     }
 
-    async fn fetch_token(&self) -> Result<String> {
-        let tok = self
-            .inner
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_secrets](crate::traits::SecretManagerService::list_secrets).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_secrets_stream(
+        &self,
+        req: crate::model::ListSecretsRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { crate::traits::SecretManagerService::list_secrets(&client, req, options).await }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_secret_versions](crate::traits::SecretManagerService::list_secret_versions).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_secret_versions_stream(
+        &self,
+        req: crate::model::ListSecretVersionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move {
+                crate::traits::SecretManagerService::list_secret_versions(&client, req, options)
+                    .await
+            }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    async fn fetch_headers(&self) -> Result<reqwest::header::HeaderMap> {
+        self.inner
             .cred
-            .access_token()
+            .headers()
             .await
-            .map_err(Error::authentication)?;
-        Ok(tok.value)
+            .map_err(Error::authentication)
     }
 
     async fn execute<I: serde::ser::Serialize, O: serde::de::DeserializeOwned>(
-        access_token: String,
+        &self,
         mut builder: reqwest::RequestBuilder,
         body: Option<I>,
+        options: gax::options::RequestOptions,
+        default_idempotency: bool,
     ) -> Result<O> {
-        builder = builder.bearer_auth(access_token);
         if let Some(body) = body {
             builder = builder.json(&body);
         }
-        let resp = builder.send().await.map_err(Error::io)?;
+        builder = builder.header(reqwest::header::USER_AGENT, &self.inner.user_agent);
+        let options = options
+            .merge(&self.inner.options)
+            .set_default_idempotency(default_idempotency);
+        if let Some(project) = options.user_project() {
+            builder = builder.header("x-goog-user-project", project);
+        }
+        builder = builder.headers(options.extra_headers().clone());
+        let has_user_project = options.user_project().is_some();
+        let retry_policy = options
+            .retry_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_retry_policy);
+        let backoff_policy = options
+            .backoff_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_backoff_policy);
+        let attempt = || {
+            let builder = builder.try_clone();
+            async move {
+                let builder = builder.ok_or_else(|| Error::other("cannot clone request"))?;
+                let mut auth_headers = self.fetch_headers().await?;
+                if has_user_project {
+                    // The request options take precedence over the credential.
+                    auth_headers.remove("x-goog-user-project");
+                }
+                Self::execute_attempt(auth_headers, builder).await
+            }
+        };
+        gax::retry_loop_internal::retry_loop(
+            attempt,
+            gax::retry_loop_internal::sleep,
+            &options,
+            retry_policy,
+            backoff_policy,
+        )
+        .await
+    }
+
+    async fn execute_attempt<O: serde::de::DeserializeOwned>(
+        auth_headers: reqwest::header::HeaderMap,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<O> {
+        let resp = builder
+            .headers(auth_headers)
+            .send()
+            .await
+            .map_err(Error::io)?;
         if !resp.status().is_success() {
             let status = resp.status().as_u16();
             let headers = gax::error::convert_headers(resp.headers());
@@ -103,6 +200,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn list_secrets(
         &self,
         req: crate::model::ListSecretsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListSecretsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -118,8 +216,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .map_err(Error::other)?;
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Creates a new [Secret][google.cloud.secretmanager.v1.Secret] containing no
@@ -127,6 +224,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn create_secret(
         &self,
         req: crate::model::CreateSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -138,8 +236,8 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .query(&[("alt", "json")]);
         let builder =
             gax::query_parameter::add(builder, "secretId", &req.secret_id).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req.secret)).await
+        self.execute(builder, Some(req.secret), options, false)
+            .await
     }
 
     /// Creates a new [SecretVersion][google.cloud.secretmanager.v1.SecretVersion]
@@ -148,6 +246,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn add_secret_version(
         &self,
         req: crate::model::AddSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -157,22 +256,21 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.parent,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Gets metadata for a given [Secret][google.cloud.secretmanager.v1.Secret].
     async fn get_secret(
         &self,
         req: crate::model::GetSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
             .get(format!("{}/v1/{}", inner_client.endpoint, req.name,))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Updates metadata of an existing
@@ -180,6 +278,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn update_secret(
         &self,
         req: crate::model::UpdateSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -198,12 +297,16 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             &serde_json::to_value(&req.update_mask).map_err(Error::serde)?,
         )
         .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req.secret)).await
+        self.execute(builder, Some(req.secret), options, false)
+            .await
     }
 
     /// Deletes a [Secret][google.cloud.secretmanager.v1.Secret].
-    async fn delete_secret(&self, req: crate::model::DeleteSecretRequest) -> Result<wkt::Empty> {
+    async fn delete_secret(
+        &self,
+        req: crate::model::DeleteSecretRequest,
+        options: gax::options::RequestOptions,
+    ) -> Result<wkt::Empty> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
@@ -211,8 +314,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .query(&[("alt", "json")]);
         let builder =
             gax::query_parameter::add(builder, "etag", &req.etag).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Lists [SecretVersions][google.cloud.secretmanager.v1.SecretVersion]. This
@@ -220,6 +322,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn list_secret_versions(
         &self,
         req: crate::model::ListSecretVersionsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListSecretVersionsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -235,8 +338,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .map_err(Error::other)?;
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Gets metadata for a
@@ -247,14 +349,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn get_secret_version(
         &self,
         req: crate::model::GetSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
             .get(format!("{}/v1/{}", inner_client.endpoint, req.name,))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Accesses a [SecretVersion][google.cloud.secretmanager.v1.SecretVersion].
@@ -265,14 +367,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn access_secret_version(
         &self,
         req: crate::model::AccessSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::AccessSecretVersionResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
             .get(format!("{}/v1/{}:access", inner_client.endpoint, req.name,))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Disables a [SecretVersion][google.cloud.secretmanager.v1.SecretVersion].
@@ -283,14 +385,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn disable_secret_version(
         &self,
         req: crate::model::DisableSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
             .post(format!("{}/v1/{}:disable", inner_client.endpoint, req.name,))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Enables a [SecretVersion][google.cloud.secretmanager.v1.SecretVersion].
@@ -301,14 +403,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn enable_secret_version(
         &self,
         req: crate::model::EnableSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
             .post(format!("{}/v1/{}:enable", inner_client.endpoint, req.name,))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Destroys a [SecretVersion][google.cloud.secretmanager.v1.SecretVersion].
@@ -320,14 +422,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn destroy_secret_version(
         &self,
         req: crate::model::DestroySecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
             .post(format!("{}/v1/{}:destroy", inner_client.endpoint, req.name,))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Sets the access control policy on the specified secret. Replaces any
@@ -340,6 +442,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn set_iam_policy(
         &self,
         req: iam_v1::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<iam_v1::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -349,8 +452,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.resource,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Gets the access control policy for a secret.
@@ -358,6 +460,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn get_iam_policy(
         &self,
         req: iam_v1::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<iam_v1::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -373,8 +476,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             &serde_json::to_value(&req.options).map_err(Error::serde)?,
         )
         .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Returns permissions that a caller has for the specified secret.
@@ -387,6 +489,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn test_iam_permissions(
         &self,
         req: iam_v1::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<iam_v1::model::TestIamPermissionsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -396,8 +499,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.resource,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 }
 
@@ -418,41 +520,113 @@ impl Locations {
         Self::new_with_config(crate::ConfigBuilder::default()).await
     }
 
-    pub async fn new_with_config(conf: crate::ConfigBuilder) -> Result<Self> {
+    pub async fn new_with_config(mut conf: crate::ConfigBuilder) -> Result<Self> {
+        let http_client = match conf.client.take() {
+            Some(client) => client,
+            None => conf.build_client()?,
+        };
+        let cred = match conf.cred.take() {
+            Some(cred) => cred,
+            None => crate::ConfigBuilder::default_credential().await?,
+        };
         let inner = InnerClient {
-            http_client: conf
-                .client
-                .unwrap_or(crate::ConfigBuilder::default_client()),
-            cred: conf
-                .cred
-                .unwrap_or(crate::ConfigBuilder::default_credential().await?),
+            http_client,
+            cred,
+            user_agent: conf.user_agent(),
             endpoint: conf.endpoint.unwrap_or(crate::DEFAULT_HOST.to_string()),
+            options: conf.request_options,
         };
         Ok(Self {
             inner: Arc::new(inner),
         })
     }
 
-    async fn fetch_token(&self) -> Result<String> {
-        let tok = self
-            .inner
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_locations](crate::traits::Locations::list_locations).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_locations_stream(
+        &self,
+        req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<location::model::ListLocationsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { crate::traits::Locations::list_locations(&client, req, options).await }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    async fn fetch_headers(&self) -> Result<reqwest::header::HeaderMap> {
+        self.inner
             .cred
-            .access_token()
+            .headers()
             .await
-            .map_err(Error::authentication)?;
-        Ok(tok.value)
+            .map_err(Error::authentication)
     }
 
     async fn execute<I: serde::ser::Serialize, O: serde::de::DeserializeOwned>(
-        access_token: String,
+        &self,
         mut builder: reqwest::RequestBuilder,
         body: Option<I>,
+        options: gax::options::RequestOptions,
+        default_idempotency: bool,
     ) -> Result<O> {
-        builder = builder.bearer_auth(access_token);
         if let Some(body) = body {
             builder = builder.json(&body);
         }
-        let resp = builder.send().await.map_err(Error::io)?;
+        builder = builder.header(reqwest::header::USER_AGENT, &self.inner.user_agent);
+        let options = options
+            .merge(&self.inner.options)
+            .set_default_idempotency(default_idempotency);
+        if let Some(project) = options.user_project() {
+            builder = builder.header("x-goog-user-project", project);
+        }
+        builder = builder.headers(options.extra_headers().clone());
+        let has_user_project = options.user_project().is_some();
+        let retry_policy = options
+            .retry_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_retry_policy);
+        let backoff_policy = options
+            .backoff_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_backoff_policy);
+        let attempt = || {
+            let builder = builder.try_clone();
+            async move {
+                let builder = builder.ok_or_else(|| Error::other("cannot clone request"))?;
+                let mut auth_headers = self.fetch_headers().await?;
+                if has_user_project {
+                    // The request options take precedence over the credential.
+                    auth_headers.remove("x-goog-user-project");
+                }
+                Self::execute_attempt(auth_headers, builder).await
+            }
+        };
+        gax::retry_loop_internal::retry_loop(
+            attempt,
+            gax::retry_loop_internal::sleep,
+            &options,
+            retry_policy,
+            backoff_policy,
+        )
+        .await
+    }
+
+    async fn execute_attempt<O: serde::de::DeserializeOwned>(
+        auth_headers: reqwest::header::HeaderMap,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<O> {
+        let resp = builder
+            .headers(auth_headers)
+            .send()
+            .await
+            .map_err(Error::io)?;
         if !resp.status().is_success() {
             let status = resp.status().as_u16();
             let headers = gax::error::convert_headers(resp.headers());
@@ -469,6 +643,7 @@ impl crate::traits::Locations for Locations {
     async fn list_locations(
         &self,
         req: location::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<location::model::ListLocationsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -484,21 +659,20 @@ impl crate::traits::Locations for Locations {
             gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token)
             .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Gets information about a location.
     async fn get_location(
         &self,
         req: location::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<location::model::Location> {
         let inner_client = self.inner.clone();
         let builder = inner_client
             .http_client
             .get(format!("{}/v1/{}", inner_client.endpoint, req.name,))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 }
@@ -58,6 +58,12 @@ impl SetIamPolicyRequest {
     }
 }
 
+impl wkt::Message for SetIamPolicyRequest {
+    fn typename() -> &'static str {
+        "google.iam.v1.SetIamPolicyRequest"
+    }
+}
+
 /// Request message for `GetIamPolicy` method.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -87,6 +93,12 @@ impl GetIamPolicyRequest {
     }
 }
 
+impl wkt::Message for GetIamPolicyRequest {
+    fn typename() -> &'static str {
+        "google.iam.v1.GetIamPolicyRequest"
+    }
+}
+
 /// Request message for `TestIamPermissions` method.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -118,6 +130,12 @@ impl TestIamPermissionsRequest {
     }
 }
 
+impl wkt::Message for TestIamPermissionsRequest {
+    fn typename() -> &'static str {
+        "google.iam.v1.TestIamPermissionsRequest"
+    }
+}
+
 /// Response message for `TestIamPermissions` method.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -137,6 +155,12 @@ impl TestIamPermissionsResponse {
     }
 }
 
+impl wkt::Message for TestIamPermissionsResponse {
+    fn typename() -> &'static str {
+        "google.iam.v1.TestIamPermissionsResponse"
+    }
+}
+
 /// Encapsulates settings provided to GetIamPolicy.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -172,6 +196,12 @@ impl GetPolicyOptions {
     }
 }
 
+impl wkt::Message for GetPolicyOptions {
+    fn typename() -> &'static str {
+        "google.iam.v1.GetPolicyOptions"
+    }
+}
+
 /// An Identity and Access Management (IAM) policy, which specifies access
 /// controls for Google Cloud resources.
 ///
@@ -334,6 +364,12 @@ impl Policy {
     }
 }
 
+impl wkt::Message for Policy {
+    fn typename() -> &'static str {
+        "google.iam.v1.Policy"
+    }
+}
+
 /// Associates `members`, or principals, with a `role`.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -425,6 +461,12 @@ impl Binding {
     }
 }
 
+impl wkt::Message for Binding {
+    fn typename() -> &'static str {
+        "google.iam.v1.Binding"
+    }
+}
+
 /// Specifies the audit configuration for a service.
 /// The configuration determines which permission types are logged, and what
 /// identities, if any, are exempted from logging.
@@ -509,6 +551,12 @@ impl AuditConfig {
     }
 }
 
+impl wkt::Message for AuditConfig {
+    fn typename() -> &'static str {
+        "google.iam.v1.AuditConfig"
+    }
+}
+
 /// Provides the configuration for logging a type of permissions.
 /// Example:
 ///
@@ -559,6 +607,12 @@ impl AuditLogConfig {
     }
 }
 
+impl wkt::Message for AuditLogConfig {
+    fn typename() -> &'static str {
+        "google.iam.v1.AuditLogConfig"
+    }
+}
+
 /// Defines additional types related to AuditLogConfig
 pub mod audit_log_config {
 
@@ -627,6 +681,12 @@ impl PolicyDelta {
     }
 }
 
+impl wkt::Message for PolicyDelta {
+    fn typename() -> &'static str {
+        "google.iam.v1.PolicyDelta"
+    }
+}
+
 /// One delta entry for Binding. Each individual change (only one member in each
 /// entry) to a binding will be a separate entry.
 #[serde_with::serde_as]
@@ -678,6 +738,12 @@ impl BindingDelta {
     }
 }
 
+impl wkt::Message for BindingDelta {
+    fn typename() -> &'static str {
+        "google.iam.v1.BindingDelta"
+    }
+}
+
 /// Defines additional types related to BindingDelta
 pub mod binding_delta {
 
@@ -766,6 +832,12 @@ impl AuditConfigDelta {
     }
 }
 
+impl wkt::Message for AuditConfigDelta {
+    fn typename() -> &'static str {
+        "google.iam.v1.AuditConfigDelta"
+    }
+}
+
 /// Defines additional types related to AuditConfigDelta
 pub mod audit_config_delta {
 
@@ -58,6 +58,7 @@ pub trait Iampolicy: Send + Sync {
     fn set_iam_policy(
         &self,
         _req: crate::model::SetIamPolicyRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Policy>> + Send {
         std::future::ready::<crate::Result<crate::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -69,6 +70,7 @@ pub trait Iampolicy: Send + Sync {
     fn get_iam_policy(
         &self,
         _req: crate::model::GetIamPolicyRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Policy>> + Send {
         std::future::ready::<crate::Result<crate::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -85,6 +87,7 @@ pub trait Iampolicy: Send + Sync {
     fn test_iam_permissions(
         &self,
         _req: crate::model::TestIamPermissionsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::TestIamPermissionsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::TestIamPermissionsResponse>>(Err(
@@ -23,6 +23,8 @@ struct InnerClient {
     http_client: reqwest::Client,
     cred: Credential,
     endpoint: String,
+    user_agent: String,
+    options: gax::options::RequestOptions,
 }
 
 #[derive(serde::Serialize)]
@@ -70,41 +72,93 @@ impl Iampolicy {
         Self::new_with_config(crate::ConfigBuilder::default()).await
     }
 
-    pub async fn new_with_config(conf: crate::ConfigBuilder) -> Result<Self> {
+    pub async fn new_with_config(mut conf: crate::ConfigBuilder) -> Result<Self> {
+        let http_client = match conf.client.take() {
+            Some(client) => client,
+            None => conf.build_client()?,
+        };
+        let cred = match conf.cred.take() {
+            Some(cred) => cred,
+            None => crate::ConfigBuilder::default_credential().await?,
+        };
         let inner = InnerClient {
-            http_client: conf
-                .client
-                .unwrap_or(crate::ConfigBuilder::default_client()),
-            cred: conf
-                .cred
-                .unwrap_or(crate::ConfigBuilder::default_credential().await?),
+            http_client,
+            cred,
+            user_agent: conf.user_agent(),
             endpoint: conf.endpoint.unwrap_or(crate::DEFAULT_HOST.to_string()),
+            options: conf.request_options,
         };
         Ok(Self {
             inner: Arc::new(inner),
         })
     }
 
-    async fn fetch_token(&self) -> Result<String> {
-        let tok = self
-            .inner
+    async fn fetch_headers(&self) -> Result<reqwest::header::HeaderMap> {
+        self.inner
             .cred
-            .access_token()
+            .headers()
             .await
-            .map_err(Error::authentication)?;
-        Ok(tok.value)
+            .map_err(Error::authentication)
     }
 
     async fn execute<I: serde::ser::Serialize, O: serde::de::DeserializeOwned>(
-        access_token: String,
+        &self,
         mut builder: reqwest::RequestBuilder,
         body: Option<I>,
+        options: gax::options::RequestOptions,
+        default_idempotency: bool,
     ) -> Result<O> {
-        builder = builder.bearer_auth(access_token);
         if let Some(body) = body {
             builder = builder.json(&body);
         }
-        let resp = builder.send().await.map_err(Error::io)?;
+        builder = builder.header(reqwest::header::USER_AGENT, &self.inner.user_agent);
+        let options = options
+            .merge(&self.inner.options)
+            .set_default_idempotency(default_idempotency);
+        if let Some(project) = options.user_project() {
+            builder = builder.header("x-goog-user-project", project);
+        }
+        builder = builder.headers(options.extra_headers().clone());
+        let has_user_project = options.user_project().is_some();
+        let retry_policy = options
+            .retry_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_retry_policy);
+        let backoff_policy = options
+            .backoff_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_backoff_policy);
+        let attempt = || {
+            let builder = builder.try_clone();
+            async move {
+                let builder = builder.ok_or_else(|| Error::other("cannot clone request"))?;
+                let mut auth_headers = self.fetch_headers().await?;
+                if has_user_project {
+                    // The request options take precedence over the credential.
+                    auth_headers.remove("x-goog-user-project");
+                }
+                Self::execute_attempt(auth_headers, builder).await
+            }
+        };
+        gax::retry_loop_internal::retry_loop(
+            attempt,
+            gax::retry_loop_internal::sleep,
+            &options,
+            retry_policy,
+            backoff_policy,
+        )
+        .await
+    }
+
+    async fn execute_attempt<O: serde::de::DeserializeOwned>(
+        auth_headers: reqwest::header::HeaderMap,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<O> {
+        let resp = builder
+            .headers(auth_headers)
+            .send()
+            .await
+            .map_err(Error::io)?;
         if !resp.status().is_success() {
             let status = resp.status().as_u16();
             let headers = gax::error::convert_headers(resp.headers());
@@ -125,6 +179,7 @@ impl crate::traits::Iampolicy for Iampolicy {
     async fn set_iam_policy(
         &self,
         req: crate::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -134,8 +189,7 @@ impl crate::traits::Iampolicy for Iampolicy {
                 inner_client.endpoint, req.resource,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Gets the access control policy for a resource. Returns an empty policy
@@ -143,6 +197,7 @@ impl crate::traits::Iampolicy for Iampolicy {
     async fn get_iam_policy(
         &self,
         req: crate::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -152,8 +207,7 @@ impl crate::traits::Iampolicy for Iampolicy {
                 inner_client.endpoint, req.resource,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Returns permissions that a caller has on the specified resource. If the
@@ -166,6 +220,7 @@ impl crate::traits::Iampolicy for Iampolicy {
     async fn test_iam_permissions(
         &self,
         req: crate::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::TestIamPermissionsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -175,7 +230,6 @@ impl crate::traits::Iampolicy for Iampolicy {
                 inner_client.endpoint, req.resource,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 }
@@ -41,6 +41,24 @@ impl ListLocationsResponse {
     }
 }
 
+impl wkt::Message for ListLocationsResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListLocationsResponse"
+    }
+}
+
+impl gax::paginator::PageableResponse for ListLocationsResponse {
+    type PageItem = crate::model::Location;
+
+    fn items(self) -> Vec<Self::PageItem> {
+        self.locations
+    }
+
+    fn next_page_token(&self) -> String {
+        gax::paginator::extract_token(self.next_page_token.clone())
+    }
+}
+
 /// A resource that represents a Google Cloud location.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -103,6 +121,12 @@ impl Location {
     }
 }
 
+impl wkt::Message for Location {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Location"
+    }
+}
+
 /// Response message for SecretManagerService.ListSecrets.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -142,6 +166,24 @@ impl ListSecretsResponse {
     }
 }
 
+impl wkt::Message for ListSecretsResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretsResponse"
+    }
+}
+
+impl gax::paginator::PageableResponse for ListSecretsResponse {
+    type PageItem = crate::model::Secret;
+
+    fn items(self) -> Vec<Self::PageItem> {
+        self.secrets
+    }
+
+    fn next_page_token(&self) -> String {
+        gax::paginator::extract_token(self.next_page_token.clone())
+    }
+}
+
 /// A Secret is a logical secret whose value and versions can
 /// be accessed.
 ///
@@ -333,6 +375,12 @@ impl Secret {
     }
 }
 
+impl wkt::Message for Secret {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Secret"
+    }
+}
+
 /// A policy that defines the replication and encryption configuration of data.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -360,6 +408,12 @@ impl Replication {
     }
 }
 
+impl wkt::Message for Replication {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Replication"
+    }
+}
+
 /// A replication policy that replicates the Secret payload without any
 /// restrictions.
 #[serde_with::serde_as]
@@ -389,6 +443,12 @@ impl Automatic {
     }
 }
 
+impl wkt::Message for Automatic {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Automatic"
+    }
+}
+
 /// Configuration for encrypting secret payloads using customer-managed
 /// encryption keys (CMEK).
 #[serde_with::serde_as]
@@ -418,6 +478,12 @@ impl CustomerManagedEncryption {
     }
 }
 
+impl wkt::Message for CustomerManagedEncryption {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.CustomerManagedEncryption"
+    }
+}
+
 /// A replication policy that replicates the Secret payload into the
 /// locations specified in Secret.replication.user_managed.replicas
 #[serde_with::serde_as]
@@ -439,6 +505,12 @@ impl UserManaged {
     }
 }
 
+impl wkt::Message for UserManaged {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.UserManaged"
+    }
+}
+
 /// Represents a Replica for this Secret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -478,6 +550,12 @@ impl Replica {
     }
 }
 
+impl wkt::Message for Replica {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Replica"
+    }
+}
+
 /// A Pub/Sub topic which Secret Manager will publish to when control plane
 /// events occur on this secret.
 #[serde_with::serde_as]
@@ -501,6 +579,12 @@ impl Topic {
     }
 }
 
+impl wkt::Message for Topic {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Topic"
+    }
+}
+
 /// The rotation time and period for a Secret. At next_rotation_time, Secret
 /// Manager will send a Pub/Sub notification to the topics configured on the
 /// Secret. Secret.topics must be set to configure rotation.
@@ -539,6 +623,12 @@ impl Rotation {
     }
 }
 
+impl wkt::Message for Rotation {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Rotation"
+    }
+}
+
 /// Request message for SecretManagerService.AddSecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -593,6 +683,12 @@ impl AddSecretVersionRequest {
     }
 }
 
+impl wkt::Message for AddSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AddSecretVersionRequest"
+    }
+}
+
 /// A secret payload resource in the Secret Manager API. This contains the
 /// sensitive secret payload that is associated with a SecretVersion.
 #[serde_with::serde_as]
@@ -633,6 +729,12 @@ impl SecretPayload {
     }
 }
 
+impl wkt::Message for SecretPayload {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.SecretPayload"
+    }
+}
+
 /// A secret version resource in the Secret Manager API.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -745,6 +847,12 @@ impl SecretVersion {
     }
 }
 
+impl wkt::Message for SecretVersion {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.SecretVersion"
+    }
+}
+
 /// The replication status of a SecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -783,6 +891,12 @@ impl ReplicationStatus {
     }
 }
 
+impl wkt::Message for ReplicationStatus {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ReplicationStatus"
+    }
+}
+
 /// The replication status of a SecretVersion using automatic replication.
 ///
 /// Only populated if the parent Secret has an automatic replication
@@ -810,6 +924,12 @@ impl AutomaticStatus {
     }
 }
 
+impl wkt::Message for AutomaticStatus {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AutomaticStatus"
+    }
+}
+
 /// Describes the status of customer-managed encryption.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -830,6 +950,12 @@ impl CustomerManagedEncryptionStatus {
     }
 }
 
+impl wkt::Message for CustomerManagedEncryptionStatus {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.CustomerManagedEncryptionStatus"
+    }
+}
+
 /// The replication status of a SecretVersion using user-managed
 /// replication.
 ///
@@ -852,6 +978,12 @@ impl UserManagedStatus {
     }
 }
 
+impl wkt::Message for UserManagedStatus {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.UserManagedStatus"
+    }
+}
+
 /// Describes the status of a user-managed replica for the SecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -886,6 +1018,12 @@ impl ReplicaStatus {
     }
 }
 
+impl wkt::Message for ReplicaStatus {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ReplicaStatus"
+    }
+}
+
 /// A generic empty message that you can re-use to avoid defining duplicated
 /// empty messages in your APIs. A typical example is to use it as the request
 /// or the response type of an API method. For instance:
@@ -903,6 +1041,12 @@ pub struct Empty {}
 
 impl Empty {}
 
+impl wkt::Message for Empty {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Empty"
+    }
+}
+
 /// Response message for SecretManagerService.ListSecretVersions.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -942,6 +1086,24 @@ impl ListSecretVersionsResponse {
     }
 }
 
+impl wkt::Message for ListSecretVersionsResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretVersionsResponse"
+    }
+}
+
+impl gax::paginator::PageableResponse for ListSecretVersionsResponse {
+    type PageItem = crate::model::SecretVersion;
+
+    fn items(self) -> Vec<Self::PageItem> {
+        self.versions
+    }
+
+    fn next_page_token(&self) -> String {
+        gax::paginator::extract_token(self.next_page_token.clone())
+    }
+}
+
 /// Response message for SecretManagerService.AccessSecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -971,6 +1133,12 @@ impl AccessSecretVersionResponse {
     }
 }
 
+impl wkt::Message for AccessSecretVersionResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AccessSecretVersionResponse"
+    }
+}
+
 /// Request message for SecretManagerService.DisableSecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1039,6 +1207,12 @@ impl DisableSecretVersionRequest {
     }
 }
 
+impl wkt::Message for DisableSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.DisableSecretVersionRequest"
+    }
+}
+
 /// Request message for SecretManagerService.EnableSecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1107,6 +1281,12 @@ impl EnableSecretVersionRequest {
     }
 }
 
+impl wkt::Message for EnableSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.EnableSecretVersionRequest"
+    }
+}
+
 /// Request message for SecretManagerService.DestroySecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1175,6 +1355,12 @@ impl DestroySecretVersionRequest {
     }
 }
 
+impl wkt::Message for DestroySecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.DestroySecretVersionRequest"
+    }
+}
+
 /// Request message for `SetIamPolicy` method.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1245,6 +1431,12 @@ impl SetIamPolicyRequest {
     }
 }
 
+impl wkt::Message for SetIamPolicyRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.SetIamPolicyRequest"
+    }
+}
+
 /// An Identity and Access Management (IAM) policy, which specifies access
 /// controls for Google Cloud resources.
 ///
@@ -1404,6 +1596,12 @@ impl Policy {
     }
 }
 
+impl wkt::Message for Policy {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Policy"
+    }
+}
+
 /// Associates `members`, or principals, with a `role`.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1538,6 +1736,12 @@ impl Binding {
     }
 }
 
+impl wkt::Message for Binding {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Binding"
+    }
+}
+
 /// Represents a textual expression in the Common Expression Language (CEL)
 /// syntax. CEL is a C-like expression language. The syntax and semantics of CEL
 /// are documented at https://github.com/google/cel-spec.
@@ -1626,6 +1830,12 @@ impl Expr {
     }
 }
 
+impl wkt::Message for Expr {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.Expr"
+    }
+}
+
 /// Specifies the audit configuration for a service.
 /// The configuration determines which permission types are logged, and what
 /// identities, if any, are exempted from logging.
@@ -1710,6 +1920,12 @@ impl AuditConfig {
     }
 }
 
+impl wkt::Message for AuditConfig {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AuditConfig"
+    }
+}
+
 /// Provides the configuration for logging a type of permissions.
 /// Example:
 ///
@@ -1759,6 +1975,12 @@ impl AuditLogConfig {
     }
 }
 
+impl wkt::Message for AuditLogConfig {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AuditLogConfig"
+    }
+}
+
 /// Request message for `TestIamPermissions` method.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1816,6 +2038,12 @@ impl TestIamPermissionsRequest {
     }
 }
 
+impl wkt::Message for TestIamPermissionsRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.TestIamPermissionsRequest"
+    }
+}
+
 /// Response message for `TestIamPermissions` method.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1835,6 +2063,12 @@ impl TestIamPermissionsResponse {
     }
 }
 
+impl wkt::Message for TestIamPermissionsResponse {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.TestIamPermissionsResponse"
+    }
+}
+
 /// The request message for ListLocations.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1890,6 +2124,12 @@ impl ListLocationsRequest {
     }
 }
 
+impl wkt::Message for ListLocationsRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListLocationsRequest"
+    }
+}
+
 /// The request message for GetLocation.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1923,6 +2163,12 @@ impl GetLocationRequest {
     }
 }
 
+impl wkt::Message for GetLocationRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetLocationRequest"
+    }
+}
+
 /// The request message for ListSecrets.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1981,6 +2227,12 @@ impl ListSecretsRequest {
     }
 }
 
+impl wkt::Message for ListSecretsRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretsRequest"
+    }
+}
+
 /// The request message for CreateSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2025,6 +2277,12 @@ impl CreateSecretRequest {
     }
 }
 
+impl wkt::Message for CreateSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.CreateSecretRequest"
+    }
+}
+
 /// The request message for ListSecretsByProjectAndLocation.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2095,6 +2353,12 @@ impl ListSecretsByProjectAndLocationRequest {
     }
 }
 
+impl wkt::Message for ListSecretsByProjectAndLocationRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretsByProjectAndLocationRequest"
+    }
+}
+
 /// The request message for CreateSecretByProjectAndLocation.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2151,6 +2415,12 @@ impl CreateSecretByProjectAndLocationRequest {
     }
 }
 
+impl wkt::Message for CreateSecretByProjectAndLocationRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.CreateSecretByProjectAndLocationRequest"
+    }
+}
+
 /// The request message for GetSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2184,6 +2454,12 @@ impl GetSecretRequest {
     }
 }
 
+impl wkt::Message for GetSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetSecretRequest"
+    }
+}
+
 /// The request message for DeleteSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2229,6 +2505,12 @@ impl DeleteSecretRequest {
     }
 }
 
+impl wkt::Message for DeleteSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.DeleteSecretRequest"
+    }
+}
+
 /// The request message for UpdateSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2281,6 +2563,12 @@ impl UpdateSecretRequest {
     }
 }
 
+impl wkt::Message for UpdateSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.UpdateSecretRequest"
+    }
+}
+
 /// The request message for GetSecretByProjectAndLocationAndSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2326,6 +2614,12 @@ impl GetSecretByProjectAndLocationAndSecretRequest {
     }
 }
 
+impl wkt::Message for GetSecretByProjectAndLocationAndSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetSecretByProjectAndLocationAndSecretRequest"
+    }
+}
+
 /// The request message for DeleteSecretByProjectAndLocationAndSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2383,6 +2677,12 @@ impl DeleteSecretByProjectAndLocationAndSecretRequest {
     }
 }
 
+impl wkt::Message for DeleteSecretByProjectAndLocationAndSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.DeleteSecretByProjectAndLocationAndSecretRequest"
+    }
+}
+
 /// The request message for UpdateSecretByProjectAndLocationAndSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2447,6 +2747,12 @@ impl UpdateSecretByProjectAndLocationAndSecretRequest {
     }
 }
 
+impl wkt::Message for UpdateSecretByProjectAndLocationAndSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.UpdateSecretByProjectAndLocationAndSecretRequest"
+    }
+}
+
 /// The request message for ListSecretVersions.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2517,6 +2823,12 @@ impl ListSecretVersionsRequest {
     }
 }
 
+impl wkt::Message for ListSecretVersionsRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretVersionsRequest"
+    }
+}
+
 /// The request message for ListSecretVersionsByProjectAndLocationAndSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2599,6 +2911,12 @@ impl ListSecretVersionsByProjectAndLocationAndSecretRequest {
     }
 }
 
+impl wkt::Message for ListSecretVersionsByProjectAndLocationAndSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.ListSecretVersionsByProjectAndLocationAndSecretRequest"
+    }
+}
+
 /// The request message for GetSecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2644,6 +2962,12 @@ impl GetSecretVersionRequest {
     }
 }
 
+impl wkt::Message for GetSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetSecretVersionRequest"
+    }
+}
+
 /// The request message for GetSecretVersionByProjectAndLocationAndSecretAndVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2701,6 +3025,12 @@ impl GetSecretVersionByProjectAndLocationAndSecretAndVersionRequest {
     }
 }
 
+impl wkt::Message for GetSecretVersionByProjectAndLocationAndSecretAndVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetSecretVersionByProjectAndLocationAndSecretAndVersionRequest"
+    }
+}
+
 /// The request message for AccessSecretVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2746,6 +3076,12 @@ impl AccessSecretVersionRequest {
     }
 }
 
+impl wkt::Message for AccessSecretVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AccessSecretVersionRequest"
+    }
+}
+
 /// The request message for AccessSecretVersionByProjectAndLocationAndSecretAndVersion.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2803,6 +3139,12 @@ impl AccessSecretVersionByProjectAndLocationAndSecretAndVersionRequest {
     }
 }
 
+impl wkt::Message for AccessSecretVersionByProjectAndLocationAndSecretAndVersionRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.AccessSecretVersionByProjectAndLocationAndSecretAndVersionRequest"
+    }
+}
+
 /// The request message for GetIamPolicy.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2863,6 +3205,12 @@ impl GetIamPolicyRequest {
     }
 }
 
+impl wkt::Message for GetIamPolicyRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetIamPolicyRequest"
+    }
+}
+
 /// The request message for GetIamPolicyByProjectAndLocationAndSecret.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -2934,3 +3282,9 @@ impl GetIamPolicyByProjectAndLocationAndSecretRequest {
         self
     }
 }
+
+impl wkt::Message for GetIamPolicyByProjectAndLocationAndSecretRequest {
+    fn typename() -> &'static str {
+        "google.cloud.secretmanager.v1.GetIamPolicyByProjectAndLocationAndSecretRequest"
+    }
+}
@@ -31,6 +31,7 @@ pub trait SecretManagerService: Send + Sync {
     fn list_locations(
         &self,
         _req: crate::model::ListLocationsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListLocationsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListLocationsResponse>>(Err(Error::other(
@@ -42,6 +43,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_location(
         &self,
         _req: crate::model::GetLocationRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Location>> + Send {
         std::future::ready::<crate::Result<crate::model::Location>>(Err(Error::other(
             "unimplemented",
@@ -52,6 +54,7 @@ pub trait SecretManagerService: Send + Sync {
     fn list_secrets(
         &self,
         _req: crate::model::ListSecretsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListSecretsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListSecretsResponse>>(Err(Error::other(
@@ -63,6 +66,7 @@ pub trait SecretManagerService: Send + Sync {
     fn create_secret(
         &self,
         _req: crate::model::CreateSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -73,6 +77,7 @@ pub trait SecretManagerService: Send + Sync {
     fn list_secrets_by_project_and_location(
         &self,
         _req: crate::model::ListSecretsByProjectAndLocationRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListSecretsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListSecretsResponse>>(Err(Error::other(
@@ -84,6 +89,7 @@ pub trait SecretManagerService: Send + Sync {
     fn create_secret_by_project_and_location(
         &self,
         _req: crate::model::CreateSecretByProjectAndLocationRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -95,6 +101,7 @@ pub trait SecretManagerService: Send + Sync {
     fn add_secret_version(
         &self,
         _req: crate::model::AddSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -106,6 +113,7 @@ pub trait SecretManagerService: Send + Sync {
     fn add_secret_version_by_project_and_location_and_secret(
         &self,
         _req: crate::model::AddSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -116,6 +124,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_secret(
         &self,
         _req: crate::model::GetSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -126,6 +135,7 @@ pub trait SecretManagerService: Send + Sync {
     fn delete_secret(
         &self,
         _req: crate::model::DeleteSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Empty>> + Send {
         std::future::ready::<crate::Result<crate::model::Empty>>(Err(Error::other("unimplemented")))
     }
@@ -134,6 +144,7 @@ pub trait SecretManagerService: Send + Sync {
     fn update_secret(
         &self,
         _req: crate::model::UpdateSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -144,6 +155,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_secret_by_project_and_location_and_secret(
         &self,
         _req: crate::model::GetSecretByProjectAndLocationAndSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -154,6 +166,7 @@ pub trait SecretManagerService: Send + Sync {
     fn delete_secret_by_project_and_location_and_secret(
         &self,
         _req: crate::model::DeleteSecretByProjectAndLocationAndSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Empty>> + Send {
         std::future::ready::<crate::Result<crate::model::Empty>>(Err(Error::other("unimplemented")))
     }
@@ -162,6 +175,7 @@ pub trait SecretManagerService: Send + Sync {
     fn update_secret_by_project_and_location_and_secret(
         &self,
         _req: crate::model::UpdateSecretByProjectAndLocationAndSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Secret>> + Send {
         std::future::ready::<crate::Result<crate::model::Secret>>(Err(Error::other(
             "unimplemented",
@@ -173,6 +187,7 @@ pub trait SecretManagerService: Send + Sync {
     fn list_secret_versions(
         &self,
         _req: crate::model::ListSecretVersionsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListSecretVersionsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListSecretVersionsResponse>>(Err(
@@ -185,6 +200,7 @@ pub trait SecretManagerService: Send + Sync {
     fn list_secret_versions_by_project_and_location_and_secret(
         &self,
         _req: crate::model::ListSecretVersionsByProjectAndLocationAndSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::ListSecretVersionsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::ListSecretVersionsResponse>>(Err(
@@ -199,6 +215,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_secret_version(
         &self,
         _req: crate::model::GetSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -212,6 +229,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         _req: crate::model::GetSecretVersionByProjectAndLocationAndSecretAndVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -225,6 +243,7 @@ pub trait SecretManagerService: Send + Sync {
     fn access_secret_version(
         &self,
         _req: crate::model::AccessSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::AccessSecretVersionResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::AccessSecretVersionResponse>>(Err(
@@ -239,6 +258,7 @@ pub trait SecretManagerService: Send + Sync {
     fn access_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         _req: crate::model::AccessSecretVersionByProjectAndLocationAndSecretAndVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::AccessSecretVersionResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::AccessSecretVersionResponse>>(Err(
@@ -253,6 +273,7 @@ pub trait SecretManagerService: Send + Sync {
     fn disable_secret_version(
         &self,
         _req: crate::model::DisableSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -266,6 +287,7 @@ pub trait SecretManagerService: Send + Sync {
     fn disable_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         _req: crate::model::DisableSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -279,6 +301,7 @@ pub trait SecretManagerService: Send + Sync {
     fn enable_secret_version(
         &self,
         _req: crate::model::EnableSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -292,6 +315,7 @@ pub trait SecretManagerService: Send + Sync {
     fn enable_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         _req: crate::model::EnableSecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -306,6 +330,7 @@ pub trait SecretManagerService: Send + Sync {
     fn destroy_secret_version(
         &self,
         _req: crate::model::DestroySecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -320,6 +345,7 @@ pub trait SecretManagerService: Send + Sync {
     fn destroy_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         _req: crate::model::DestroySecretVersionRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::SecretVersion>> + Send {
         std::future::ready::<crate::Result<crate::model::SecretVersion>>(Err(Error::other(
             "unimplemented",
@@ -334,6 +360,7 @@ pub trait SecretManagerService: Send + Sync {
     fn set_iam_policy(
         &self,
         _req: crate::model::SetIamPolicyRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Policy>> + Send {
         std::future::ready::<crate::Result<crate::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -348,6 +375,7 @@ pub trait SecretManagerService: Send + Sync {
     fn set_iam_policy_by_project_and_location_and_secret(
         &self,
         _req: crate::model::SetIamPolicyRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Policy>> + Send {
         std::future::ready::<crate::Result<crate::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -359,6 +387,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_iam_policy(
         &self,
         _req: crate::model::GetIamPolicyRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Policy>> + Send {
         std::future::ready::<crate::Result<crate::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -370,6 +399,7 @@ pub trait SecretManagerService: Send + Sync {
     fn get_iam_policy_by_project_and_location_and_secret(
         &self,
         _req: crate::model::GetIamPolicyByProjectAndLocationAndSecretRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::Policy>> + Send {
         std::future::ready::<crate::Result<crate::model::Policy>>(Err(Error::other(
             "unimplemented",
@@ -386,6 +416,7 @@ pub trait SecretManagerService: Send + Sync {
     fn test_iam_permissions(
         &self,
         _req: crate::model::TestIamPermissionsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::TestIamPermissionsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::TestIamPermissionsResponse>>(Err(
@@ -403,6 +434,7 @@ pub trait SecretManagerService: Send + Sync {
     fn test_iam_permissions_by_project_and_location_and_secret(
         &self,
         _req: crate::model::TestIamPermissionsRequest,
+        _options: gax::options::RequestOptions,
     ) -> impl std::future::Future<Output = crate::Result<crate::model::TestIamPermissionsResponse>> + Send
     {
         std::future::ready::<crate::Result<crate::model::TestIamPermissionsResponse>>(Err(
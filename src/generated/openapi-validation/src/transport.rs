@@ -23,6 +23,8 @@ struct InnerClient {
     http_client: reqwest::Client,
     cred: Credential,
     endpoint: String,
+    user_agent: String,
+    options: gax::options::RequestOptions,
 }
 
 #[derive(serde::Serialize)]
@@ -47,41 +49,205 @@ impl SecretManagerService {
         Self::new_with_config(crate::ConfigBuilder::default()).await
     }
 
-    pub async fn new_with_config(conf: crate::ConfigBuilder) -> Result<Self> {
+    pub async fn new_with_config(mut conf: crate::ConfigBuilder) -> Result<Self> {
+        let http_client = match conf.client.take() {
+            Some(client) => client,
+            None => conf.build_client()?,
+        };
+        let cred = match conf.cred.take() {
+            Some(cred) => cred,
+            None => crate::ConfigBuilder::default_credential().await?,
+        };
         let inner = InnerClient {
-            http_client: conf
-                .client
-                .unwrap_or(crate::ConfigBuilder::default_client()),
-            cred: conf
-                .cred
-                .unwrap_or(crate::ConfigBuilder::default_credential().await?),
+            http_client,
+            cred,
+            user_agent: conf.user_agent(),
             endpoint: conf.endpoint.unwrap_or(crate::DEFAULT_HOST.to_string()),
+            options: conf.request_options,
         };
         Ok(Self {
             inner: Arc::new(inner),
         })
     }
 
-    async fn fetch_token(&self) -> Result<String> {
-        let tok = self
-            .inner
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_locations](crate::traits::SecretManagerService::list_locations).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_locations_stream(
+        &self,
+        req: crate::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<crate::model::ListLocationsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move {
+                crate::traits::SecretManagerService::list_locations(&client, req, options).await
+            }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_secrets](crate::traits::SecretManagerService::list_secrets).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_secrets_stream(
+        &self,
+        req: crate::model::ListSecretsRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move { crate::traits::SecretManagerService::list_secrets(&client, req, options).await }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_secrets_by_project_and_location](crate::traits::SecretManagerService::list_secrets_by_project_and_location).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_secrets_by_project_and_location_stream(
+        &self,
+        req: crate::model::ListSecretsByProjectAndLocationRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move {
+                crate::traits::SecretManagerService::list_secrets_by_project_and_location(
+                    &client, req, options,
+                )
+                .await
+            }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_secret_versions](crate::traits::SecretManagerService::list_secret_versions).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_secret_versions_stream(
+        &self,
+        req: crate::model::ListSecretVersionsRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move {
+                crate::traits::SecretManagerService::list_secret_versions(&client, req, options)
+                    .await
+            }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    /// Returns a [Paginator](gax::paginator::Paginator) over the pages of
+    /// [list_secret_versions_by_project_and_location_and_secret](crate::traits::SecretManagerService::list_secret_versions_by_project_and_location_and_secret).
+    ///
+    /// Use `items()` to iterate over the individual items instead.
+    pub fn list_secret_versions_by_project_and_location_and_secret_stream(
+        &self,
+        req: crate::model::ListSecretVersionsByProjectAndLocationAndSecretRequest,
+        options: gax::options::RequestOptions,
+    ) -> gax::paginator::Paginator<crate::model::ListSecretVersionsResponse, Error> {
+        let client = self.clone();
+        let token = gax::paginator::extract_token(req.page_token.clone());
+        let execute = move |token: String| {
+            let client = client.clone();
+            let req = req.clone().set_page_token(token);
+            let options = options.clone();
+            async move {
+                crate::traits::SecretManagerService::list_secret_versions_by_project_and_location_and_secret(&client, req, options).await
+            }
+        };
+        gax::paginator::Paginator::new(token, execute)
+    }
+
+    async fn fetch_headers(&self) -> Result<reqwest::header::HeaderMap> {
+        self.inner
             .cred
-            .access_token()
+            .headers()
             .await
-            .map_err(Error::authentication)?;
-        Ok(tok.value)
+            .map_err(Error::authentication)
     }
 
     async fn execute<I: serde::ser::Serialize, O: serde::de::DeserializeOwned>(
-        access_token: String,
+        &self,
         mut builder: reqwest::RequestBuilder,
         body: Option<I>,
+        options: gax::options::RequestOptions,
+        default_idempotency: bool,
     ) -> Result<O> {
-        builder = builder.bearer_auth(access_token);
         if let Some(body) = body {
             builder = builder.json(&body);
         }
-        let resp = builder.send().await.map_err(Error::io)?;
+        builder = builder.header(reqwest::header::USER_AGENT, &self.inner.user_agent);
+        let options = options
+            .merge(&self.inner.options)
+            .set_default_idempotency(default_idempotency);
+        if let Some(project) = options.user_project() {
+            builder = builder.header("x-goog-user-project", project);
+        }
+        builder = builder.headers(options.extra_headers().clone());
+        let has_user_project = options.user_project().is_some();
+        let retry_policy = options
+            .retry_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_retry_policy);
+        let backoff_policy = options
+            .backoff_policy()
+            .clone()
+            .unwrap_or_else(crate::ConfigBuilder::default_backoff_policy);
+        let attempt = || {
+            let builder = builder.try_clone();
+            async move {
+                let builder = builder.ok_or_else(|| Error::other("cannot clone request"))?;
+                let mut auth_headers = self.fetch_headers().await?;
+                if has_user_project {
+                    // The request options take precedence over the credential.
+                    auth_headers.remove("x-goog-user-project");
+                }
+                Self::execute_attempt(auth_headers, builder).await
+            }
+        };
+        gax::retry_loop_internal::retry_loop(
+            attempt,
+            gax::retry_loop_internal::sleep,
+            &options,
+            retry_policy,
+            backoff_policy,
+        )
+        .await
+    }
+
+    async fn execute_attempt<O: serde::de::DeserializeOwned>(
+        auth_headers: reqwest::header::HeaderMap,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<O> {
+        let resp = builder
+            .headers(auth_headers)
+            .send()
+            .await
+            .map_err(Error::io)?;
         if !resp.status().is_success() {
             let status = resp.status().as_u16();
             let headers = gax::error::convert_headers(resp.headers());
@@ -98,6 +264,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn list_locations(
         &self,
         req: crate::model::ListLocationsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListLocationsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -113,14 +280,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             gax::query_parameter::add(builder, "pageSize", &req.page_size).map_err(Error::other)?;
         let builder = gax::query_parameter::add(builder, "pageToken", &req.page_token)
             .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Gets information about a location.
     async fn get_location(
         &self,
         req: crate::model::GetLocationRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Location> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -130,14 +297,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Lists Secrets.
     async fn list_secrets(
         &self,
         req: crate::model::ListSecretsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListSecretsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -153,14 +320,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .map_err(Error::other)?;
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Creates a new Secret containing no SecretVersions.
     async fn create_secret(
         &self,
         req: crate::model::CreateSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -172,14 +339,15 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .query(&[("alt", "json")]);
         let builder =
             gax::query_parameter::add(builder, "secretId", &req.secret_id).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req.request_body)).await
+        self.execute(builder, Some(req.request_body), options, false)
+            .await
     }
 
     /// Lists Secrets.
     async fn list_secrets_by_project_and_location(
         &self,
         req: crate::model::ListSecretsByProjectAndLocationRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListSecretsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -195,14 +363,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .map_err(Error::other)?;
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Creates a new Secret containing no SecretVersions.
     async fn create_secret_by_project_and_location(
         &self,
         req: crate::model::CreateSecretByProjectAndLocationRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -214,8 +382,8 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .query(&[("alt", "json")]);
         let builder =
             gax::query_parameter::add(builder, "secretId", &req.secret_id).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req.request_body)).await
+        self.execute(builder, Some(req.request_body), options, false)
+            .await
     }
 
     /// Creates a new SecretVersion containing secret data and attaches
@@ -223,6 +391,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn add_secret_version(
         &self,
         req: crate::model::AddSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -232,8 +401,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Creates a new SecretVersion containing secret data and attaches
@@ -241,6 +409,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn add_secret_version_by_project_and_location_and_secret(
         &self,
         req: crate::model::AddSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -250,14 +419,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Gets metadata for a given Secret.
     async fn get_secret(
         &self,
         req: crate::model::GetSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -267,14 +436,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Deletes a Secret.
     async fn delete_secret(
         &self,
         req: crate::model::DeleteSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Empty> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -286,14 +455,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .query(&[("alt", "json")]);
         let builder =
             gax::query_parameter::add(builder, "etag", &req.etag).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Updates metadata of an existing Secret.
     async fn update_secret(
         &self,
         req: crate::model::UpdateSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -309,14 +478,15 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             &serde_json::to_value(&req.update_mask).map_err(Error::serde)?,
         )
         .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req.request_body)).await
+        self.execute(builder, Some(req.request_body), options, false)
+            .await
     }
 
     /// Gets metadata for a given Secret.
     async fn get_secret_by_project_and_location_and_secret(
         &self,
         req: crate::model::GetSecretByProjectAndLocationAndSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -326,14 +496,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Deletes a Secret.
     async fn delete_secret_by_project_and_location_and_secret(
         &self,
         req: crate::model::DeleteSecretByProjectAndLocationAndSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Empty> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -345,14 +515,14 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .query(&[("alt", "json")]);
         let builder =
             gax::query_parameter::add(builder, "etag", &req.etag).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Updates metadata of an existing Secret.
     async fn update_secret_by_project_and_location_and_secret(
         &self,
         req: crate::model::UpdateSecretByProjectAndLocationAndSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Secret> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -368,8 +538,8 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             &serde_json::to_value(&req.update_mask).map_err(Error::serde)?,
         )
         .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req.request_body)).await
+        self.execute(builder, Some(req.request_body), options, false)
+            .await
     }
 
     /// Lists SecretVersions. This call does not return secret
@@ -377,6 +547,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn list_secret_versions(
         &self,
         req: crate::model::ListSecretVersionsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListSecretVersionsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -392,8 +563,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .map_err(Error::other)?;
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Lists SecretVersions. This call does not return secret
@@ -401,6 +571,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn list_secret_versions_by_project_and_location_and_secret(
         &self,
         req: crate::model::ListSecretVersionsByProjectAndLocationAndSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::ListSecretVersionsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -416,8 +587,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             .map_err(Error::other)?;
         let builder =
             gax::query_parameter::add(builder, "filter", &req.filter).map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Gets metadata for a SecretVersion.
@@ -427,6 +597,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn get_secret_version(
         &self,
         req: crate::model::GetSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -436,8 +607,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Gets metadata for a SecretVersion.
@@ -447,6 +617,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn get_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         req: crate::model::GetSecretVersionByProjectAndLocationAndSecretAndVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -456,8 +627,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Accesses a SecretVersion. This call returns the secret data.
@@ -467,6 +637,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn access_secret_version(
         &self,
         req: crate::model::AccessSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::AccessSecretVersionResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -476,8 +647,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Accesses a SecretVersion. This call returns the secret data.
@@ -487,6 +657,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn access_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         req: crate::model::AccessSecretVersionByProjectAndLocationAndSecretAndVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::AccessSecretVersionResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -496,8 +667,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Disables a SecretVersion.
@@ -507,6 +677,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn disable_secret_version(
         &self,
         req: crate::model::DisableSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -516,8 +687,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Disables a SecretVersion.
@@ -527,6 +697,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn disable_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         req: crate::model::DisableSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -536,8 +707,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Enables a SecretVersion.
@@ -547,6 +717,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn enable_secret_version(
         &self,
         req: crate::model::EnableSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -556,8 +727,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Enables a SecretVersion.
@@ -567,6 +737,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn enable_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         req: crate::model::EnableSecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -576,8 +747,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Destroys a SecretVersion.
@@ -588,6 +758,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn destroy_secret_version(
         &self,
         req: crate::model::DestroySecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -597,8 +768,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Destroys a SecretVersion.
@@ -609,6 +779,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn destroy_secret_version_by_project_and_location_and_secret_and_version(
         &self,
         req: crate::model::DestroySecretVersionRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::SecretVersion> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -618,8 +789,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret, req.version,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Sets the access control policy on the specified secret. Replaces any
@@ -630,6 +800,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn set_iam_policy(
         &self,
         req: crate::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -639,8 +810,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Sets the access control policy on the specified secret. Replaces any
@@ -651,6 +821,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn set_iam_policy_by_project_and_location_and_secret(
         &self,
         req: crate::model::SetIamPolicyRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -660,8 +831,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Gets the access control policy for a secret.
@@ -669,6 +839,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn get_iam_policy(
         &self,
         req: crate::model::GetIamPolicyRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -684,8 +855,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             &req.options_requested_policy_version,
         )
         .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Gets the access control policy for a secret.
@@ -693,6 +863,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn get_iam_policy_by_project_and_location_and_secret(
         &self,
         req: crate::model::GetIamPolicyByProjectAndLocationAndSecretRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::Policy> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -708,8 +879,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
             &req.options_requested_policy_version,
         )
         .map_err(Error::other)?;
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, None::<NoBody>).await
+        self.execute(builder, None::<NoBody>, options, true).await
     }
 
     /// Returns permissions that a caller has for the specified secret.
@@ -722,6 +892,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn test_iam_permissions(
         &self,
         req: crate::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::TestIamPermissionsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -731,8 +902,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 
     /// Returns permissions that a caller has for the specified secret.
@@ -745,6 +915,7 @@ impl crate::traits::SecretManagerService for SecretManagerService {
     async fn test_iam_permissions_by_project_and_location_and_secret(
         &self,
         req: crate::model::TestIamPermissionsRequest,
+        options: gax::options::RequestOptions,
     ) -> Result<crate::model::TestIamPermissionsResponse> {
         let inner_client = self.inner.clone();
         let builder = inner_client
@@ -754,7 +925,6 @@ impl crate::traits::SecretManagerService for SecretManagerService {
                 inner_client.endpoint, req.project, req.location, req.secret,
             ))
             .query(&[("alt", "json")]);
-        let access_token = self.fetch_token().await?;
-        Self::execute(access_token, builder, Some(req)).await
+        self.execute(builder, Some(req), options, false).await
     }
 }
@@ -216,6 +216,12 @@ impl Color {
     }
 }
 
+impl wkt::Message for Color {
+    fn typename() -> &'static str {
+        "google.type.Color"
+    }
+}
+
 /// Represents a whole or partial calendar date, such as a birthday. The time of
 /// day and time zone are either specified elsewhere or are insignificant. The
 /// date is relative to the Gregorian Calendar. This can represent one of the
@@ -268,6 +274,12 @@ impl Date {
     }
 }
 
+impl wkt::Message for Date {
+    fn typename() -> &'static str {
+        "google.type.Date"
+    }
+}
+
 /// Represents civil time (or occasionally physical time).
 ///
 /// This type can represent a civil time in one of a few possible ways:
@@ -386,6 +398,12 @@ impl DateTime {
     }
 }
 
+impl wkt::Message for DateTime {
+    fn typename() -> &'static str {
+        "google.type.DateTime"
+    }
+}
+
 /// Defines additional types related to DateTime
 pub mod date_time {
 
@@ -435,6 +453,12 @@ impl TimeZone {
     }
 }
 
+impl wkt::Message for TimeZone {
+    fn typename() -> &'static str {
+        "google.type.TimeZone"
+    }
+}
+
 /// A representation of a decimal value, such as 2.5. Clients may convert values
 /// into language-native decimal formats, such as Java's [BigDecimal][] or
 /// Python's [decimal.Decimal][].
@@ -528,6 +552,12 @@ impl Decimal {
     }
 }
 
+impl wkt::Message for Decimal {
+    fn typename() -> &'static str {
+        "google.type.Decimal"
+    }
+}
+
 /// Represents a textual expression in the Common Expression Language (CEL)
 /// syntax. CEL is a C-like expression language. The syntax and semantics of CEL
 /// are documented at https://github.com/google/cel-spec.
@@ -616,6 +646,12 @@ impl Expr {
     }
 }
 
+impl wkt::Message for Expr {
+    fn typename() -> &'static str {
+        "google.type.Expr"
+    }
+}
+
 /// Represents a fraction in terms of a numerator divided by a denominator.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -646,6 +682,12 @@ impl Fraction {
     }
 }
 
+impl wkt::Message for Fraction {
+    fn typename() -> &'static str {
+        "google.type.Fraction"
+    }
+}
+
 /// Represents a time interval, encoded as a Timestamp start (inclusive) and a
 /// Timestamp end (exclusive).
 ///
@@ -684,6 +726,12 @@ impl Interval {
     }
 }
 
+impl wkt::Message for Interval {
+    fn typename() -> &'static str {
+        "google.type.Interval"
+    }
+}
+
 /// An object that represents a latitude/longitude pair. This is expressed as a
 /// pair of doubles to represent degrees latitude and degrees longitude. Unless
 /// specified otherwise, this must conform to the
@@ -715,6 +763,12 @@ impl LatLng {
     }
 }
 
+impl wkt::Message for LatLng {
+    fn typename() -> &'static str {
+        "google.type.LatLng"
+    }
+}
+
 /// Localized variant of a text in a particular language.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -745,6 +799,12 @@ impl LocalizedText {
     }
 }
 
+impl wkt::Message for LocalizedText {
+    fn typename() -> &'static str {
+        "google.type.LocalizedText"
+    }
+}
+
 /// Represents an amount of money with its currency type.
 #[serde_with::serde_as]
 #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -788,6 +848,12 @@ impl Money {
     }
 }
 
+impl wkt::Message for Money {
+    fn typename() -> &'static str {
+        "google.type.Money"
+    }
+}
+
 /// An object representing a phone number, suitable as an API wire format.
 ///
 /// This representation:
@@ -857,6 +923,12 @@ impl PhoneNumber {
     }
 }
 
+impl wkt::Message for PhoneNumber {
+    fn typename() -> &'static str {
+        "google.type.PhoneNumber"
+    }
+}
+
 /// Defines additional types related to PhoneNumber
 pub mod phone_number {
 
@@ -900,6 +972,12 @@ pub mod phone_number {
         }
     }
 
+    impl wkt::Message for ShortCode {
+        fn typename() -> &'static str {
+            "google.type.PhoneNumber.ShortCode"
+        }
+    }
+
     /// Required.  Either a regular number, or a short code.  New fields may be
     /// added to the oneof below in the future, so clients should ignore phone
     /// numbers for which none of the fields they coded against are set.
@@ -1113,6 +1191,12 @@ impl PostalAddress {
     }
 }
 
+impl wkt::Message for PostalAddress {
+    fn typename() -> &'static str {
+        "google.type.PostalAddress"
+    }
+}
+
 /// A quaternion is defined as the quotient of two directed lines in a
 /// three-dimensional space or equivalently as the quotient of two Euclidean
 /// vectors (https://en.wikipedia.org/wiki/Quaternion).
@@ -1217,6 +1301,12 @@ impl Quaternion {
     }
 }
 
+impl wkt::Message for Quaternion {
+    fn typename() -> &'static str {
+        "google.type.Quaternion"
+    }
+}
+
 /// Represents a time of day. The date and time zone are either not significant
 /// or are specified elsewhere. An API may choose to allow leap seconds. Related
 /// types are [google.type.Date][google.type.Date] and
@@ -1266,3 +1356,9 @@ impl TimeOfDay {
         self
     }
 }
+
+impl wkt::Message for TimeOfDay {
+    fn typename() -> &'static str {
+        "google.type.TimeOfDay"
+    }
+}
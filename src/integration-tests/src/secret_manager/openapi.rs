@@ -43,6 +43,7 @@ pub async fn run() -> Result<()> {
                                 .map(|(k, v)| (k.to_string(), v.to_string())),
                         ),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("CREATE = {create:?}");
@@ -59,6 +60,7 @@ pub async fn run() -> Result<()> {
             smo::model::GetSecretRequest::default()
                 .set_project(&project_id)
                 .set_secret(&secret_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("GET = {get:?}");
@@ -79,6 +81,7 @@ pub async fn run() -> Result<()> {
                     wkt::FieldMask::default().set_paths(["labels"].map(str::to_string).to_vec()),
                 )
                 .set_request_body(smo::model::Secret::default().set_labels(new_labels)),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("UPDATE = {update:?}");
@@ -101,6 +104,7 @@ pub async fn run() -> Result<()> {
             smo::model::DeleteSecretRequest::default()
                 .set_project(&project_id)
                 .set_secret(&secret_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("DELETE = {response:?}");
@@ -110,7 +114,10 @@ pub async fn run() -> Result<()> {
 async fn run_locations(client: &smo::SecretManagerServiceClient, project_id: &str) -> Result<()> {
     println!("\nTesting list_locations()");
     let locations = client
-        .list_locations(smo::model::ListLocationsRequest::default().set_project(project_id))
+        .list_locations(
+            smo::model::ListLocationsRequest::default().set_project(project_id),
+            gax::options::RequestOptions::default(),
+        )
         .await?;
     println!("LOCATIONS = {locations:?}");
 
@@ -130,6 +137,7 @@ async fn run_locations(client: &smo::SecretManagerServiceClient, project_id: &st
             smo::model::GetLocationRequest::default()
                 .set_project(project_id)
                 .set_location(first.location_id.clone().unwrap()),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("GET = {get:?}");
@@ -152,6 +160,7 @@ async fn run_iam(
             smo::model::GetIamPolicyRequest::default()
                 .set_project(project_id)
                 .set_secret(secret_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("POLICY = {policy:?}");
@@ -167,6 +176,7 @@ async fn run_iam(
                         .map(str::to_string)
                         .to_vec(),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {response:?}");
@@ -201,6 +211,7 @@ async fn run_iam(
                     wkt::FieldMask::default().set_paths(["bindings"].map(str::to_string).to_vec()),
                 )
                 .set_policy(new_policy),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {response:?}");
@@ -226,6 +237,7 @@ async fn run_secret_versions(
                         .set_data(bytes::Bytes::from(data))
                         .set_data_crc_32_c(checksum as i64),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("CREATE_SECRET_VERSION = {create:?}");
@@ -251,6 +263,7 @@ async fn run_secret_versions(
                 .set_project(project_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("GET_SECRET_VERSION = {get:?}");
@@ -273,6 +286,7 @@ async fn run_secret_versions(
                 .set_project(project_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("ACCESS_SECRET_VERSION = {access_secret_version:?}");
@@ -288,6 +302,7 @@ async fn run_secret_versions(
                 .set_project(project_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("DISABLE_SECRET_VERSION = {disable:?}");
@@ -299,6 +314,7 @@ async fn run_secret_versions(
                 .set_project(project_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("ENABLE_SECRET_VERSION = {enable:?}");
@@ -310,6 +326,7 @@ async fn run_secret_versions(
                 .set_project(project_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {delete:?}");
@@ -323,25 +340,16 @@ async fn get_all_secret_version_names(
     secret_id: &str,
 ) -> Result<Vec<String>> {
     let mut names = Vec::new();
-    let mut page_token = None::<String>;
-    loop {
-        let response = client
-            .list_secret_versions(
-                smo::model::ListSecretVersionsRequest::default()
-                    .set_project(project_id)
-                    .set_secret(secret_id)
-                    .set_page_token(page_token),
-            )
-            .await?;
-        response
-            .versions
-            .into_iter()
-            .filter_map(|s| s.name)
-            .for_each(|name| names.push(name));
-        page_token = response.next_page_token;
-        if page_token.as_ref().map(String::is_empty).unwrap_or(true) {
-            break;
-        }
+    let mut items = client
+        .list_secret_versions_stream(
+            smo::model::ListSecretVersionsRequest::default()
+                .set_project(project_id)
+                .set_secret(secret_id),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(version) = items.next().await {
+        names.extend(version?.name);
     }
     Ok(names)
 }
@@ -351,24 +359,14 @@ async fn get_all_secret_names(
     project_id: &str,
 ) -> Result<Vec<String>> {
     let mut names = Vec::new();
-    let mut page_token = None::<String>;
-    loop {
-        let response = client
-            .list_secrets(
-                smo::model::ListSecretsRequest::default()
-                    .set_project(project_id)
-                    .set_page_token(page_token),
-            )
-            .await?;
-        response
-            .secrets
-            .into_iter()
-            .filter_map(|s| s.name)
-            .for_each(|name| names.push(name));
-        page_token = response.next_page_token;
-        if page_token.as_ref().map(String::is_empty).unwrap_or(true) {
-            break;
-        }
+    let mut items = client
+        .list_secrets_stream(
+            smo::model::ListSecretsRequest::default().set_project(project_id),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(secret) = items.next().await {
+        names.extend(secret?.name);
     }
     Ok(names)
 }
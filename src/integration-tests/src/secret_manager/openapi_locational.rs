@@ -45,6 +45,7 @@ pub async fn run() -> Result<()> {
                 .set_request_body(smo::model::Secret::default().set_labels(
                     [("integration-test", "true")].map(|(k, v)| (k.to_string(), v.to_string())),
                 )),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("CREATE = {create:?}");
@@ -56,6 +57,7 @@ pub async fn run() -> Result<()> {
                 .set_project(&project_id)
                 .set_location(&location_id)
                 .set_secret(&secret_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("GET = {get:?}");
@@ -75,6 +77,7 @@ pub async fn run() -> Result<()> {
                     wkt::FieldMask::default().set_paths(["labels"].map(str::to_string).to_vec()),
                 )
                 .set_request_body(smo::model::Secret::default().set_labels(new_labels)),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("UPDATE = {update:?}");
@@ -99,6 +102,7 @@ pub async fn run() -> Result<()> {
                 .set_project(&project_id)
                 .set_location(&location_id)
                 .set_secret(&secret_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("DELETE = {response:?}");
@@ -121,6 +125,7 @@ async fn run_iam(
                 .set_project(project_id)
                 .set_location(location_id)
                 .set_secret(secret_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("POLICY = {policy:?}");
@@ -137,6 +142,7 @@ async fn run_iam(
                         .map(str::to_string)
                         .to_vec(),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {response:?}");
@@ -172,6 +178,7 @@ async fn run_iam(
                     wkt::FieldMask::default().set_paths(["bindings"].map(str::to_string).to_vec()),
                 )
                 .set_policy(new_policy),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {response:?}");
@@ -199,6 +206,7 @@ async fn run_secret_versions(
                         .set_data(bytes::Bytes::from(data))
                         .set_data_crc_32_c(checksum as i64),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("CREATE_SECRET_VERSION = {create:?}");
@@ -225,6 +233,7 @@ async fn run_secret_versions(
                 .set_location(location_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("GET_SECRET_VERSION = {get:?}");
@@ -250,6 +259,7 @@ async fn run_secret_versions(
             .set_location(location_id)
             .set_secret(secret_id)
             .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("ACCESS_SECRET_VERSION = {access_secret_version:?}");
@@ -266,6 +276,7 @@ async fn run_secret_versions(
                 .set_location(location_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("DISABLE_SECRET_VERSION = {disable:?}");
@@ -278,6 +289,7 @@ async fn run_secret_versions(
                 .set_location(location_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("ENABLE_SECRET_VERSION = {enable:?}");
@@ -290,6 +302,7 @@ async fn run_secret_versions(
                 .set_location(location_id)
                 .set_secret(secret_id)
                 .set_version(version_id),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {delete:?}");
@@ -304,26 +317,17 @@ async fn get_all_secret_version_names(
     secret_id: &str,
 ) -> Result<Vec<String>> {
     let mut names = Vec::new();
-    let mut page_token = None::<String>;
-    loop {
-        let response = client
-            .list_secret_versions_by_project_and_location_and_secret(
-                smo::model::ListSecretVersionsByProjectAndLocationAndSecretRequest::default()
-                    .set_project(project_id)
-                    .set_location(location_id)
-                    .set_secret(secret_id)
-                    .set_page_token(page_token),
-            )
-            .await?;
-        response
-            .versions
-            .into_iter()
-            .filter_map(|s| s.name)
-            .for_each(|name| names.push(name));
-        page_token = response.next_page_token;
-        if page_token.as_ref().map(String::is_empty).unwrap_or(true) {
-            break;
-        }
+    let mut items = client
+        .list_secret_versions_by_project_and_location_and_secret_stream(
+            smo::model::ListSecretVersionsByProjectAndLocationAndSecretRequest::default()
+                .set_project(project_id)
+                .set_location(location_id)
+                .set_secret(secret_id),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(version) = items.next().await {
+        names.extend(version?.name);
     }
     Ok(names)
 }
@@ -334,25 +338,16 @@ async fn get_all_secret_names(
     location_id: &str,
 ) -> Result<Vec<String>> {
     let mut names = Vec::new();
-    let mut page_token = None::<String>;
-    loop {
-        let response = client
-            .list_secrets_by_project_and_location(
-                smo::model::ListSecretsByProjectAndLocationRequest::default()
-                    .set_project(project_id)
-                    .set_location(location_id)
-                    .set_page_token(page_token),
-            )
-            .await?;
-        response
-            .secrets
-            .into_iter()
-            .filter_map(|s| s.name)
-            .for_each(|name| names.push(name));
-        page_token = response.next_page_token;
-        if page_token.as_ref().map(String::is_empty).unwrap_or(true) {
-            break;
-        }
+    let mut items = client
+        .list_secrets_by_project_and_location_stream(
+            smo::model::ListSecretsByProjectAndLocationRequest::default()
+                .set_project(project_id)
+                .set_location(location_id),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(secret) = items.next().await {
+        names.extend(secret?.name);
     }
     Ok(names)
 }
@@ -370,36 +365,21 @@ async fn cleanup_stale_secrets(
     let stale_deadline = wkt::Timestamp::clamp(stale_deadline.as_secs() as i64, 0);
 
     let mut stale_secrets = Vec::new();
-    let mut page_token = None::<String>;
-    loop {
-        let response = client
-            .list_secrets_by_project_and_location(
-                smo::model::ListSecretsByProjectAndLocationRequest::default()
-                    .set_project(project_id)
-                    .set_location(location_id)
-                    .set_page_token(page_token)
-                    .clone(),
-            )
-            .await?;
-        for secret in response.secrets {
-            if let Some("true") = secret.labels.get("integration-test").map(String::as_str) {
-                if let Some(true) = secret.create_time.map(|v| v < stale_deadline) {
-                    secret
-                        .name
-                        .into_iter()
-                        .for_each(|name| stale_secrets.push(name));
-                }
+    let mut items = client
+        .list_secrets_by_project_and_location_stream(
+            smo::model::ListSecretsByProjectAndLocationRequest::default()
+                .set_project(project_id)
+                .set_location(location_id),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(secret) = items.next().await {
+        let secret = secret?;
+        if let Some("true") = secret.labels.get("integration-test").map(String::as_str) {
+            if let Some(true) = secret.create_time.map(|v| v < stale_deadline) {
+                stale_secrets.extend(secret.name);
             }
         }
-        if response
-            .next_page_token
-            .as_ref()
-            .map(String::is_empty)
-            .unwrap_or(true)
-        {
-            break;
-        }
-        page_token = response.next_page_token;
     }
 
     let pending = stale_secrets
@@ -410,6 +390,7 @@ async fn cleanup_stale_secrets(
                     .set_project(project_id)
                     .set_location(location_id)
                     .set_secret(v),
+                gax::options::RequestOptions::default(),
             )
         })
         .collect::<Vec<_>>();
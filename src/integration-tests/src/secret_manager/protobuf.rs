@@ -49,6 +49,7 @@ pub async fn run() -> Result<()> {
                                 .map(|(k, v)| (k.to_string(), v.to_string())),
                         ),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("CREATE = {create:?}");
@@ -60,7 +61,10 @@ pub async fn run() -> Result<()> {
 
     println!("\nTesting get_secret()");
     let get = client
-        .get_secret(sm::model::GetSecretRequest::default().set_name(&create.name))
+        .get_secret(
+            sm::model::GetSecretRequest::default().set_name(&create.name),
+            gax::options::RequestOptions::default(),
+        )
         .await?;
     println!("GET = {get:?}");
     assert_eq!(get, create);
@@ -79,6 +83,7 @@ pub async fn run() -> Result<()> {
                         .set_name(&get.name)
                         .set_labels(new_labels),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("UPDATE = {update:?}");
@@ -97,7 +102,10 @@ pub async fn run() -> Result<()> {
 
     println!("\nTesting delete_secret()");
     let delete = client
-        .delete_secret(sm::model::DeleteSecretRequest::default().set_name(get.name))
+        .delete_secret(
+            sm::model::DeleteSecretRequest::default().set_name(get.name),
+            gax::options::RequestOptions::default(),
+        )
         .await?;
     println!("DELETE = {delete:?}");
 
@@ -109,6 +117,7 @@ async fn run_locations(client: &sm::LocationsClient, project_id: &str) -> Result
     let locations = client
         .list_locations(
             loc::model::ListLocationsRequest::default().set_name(format!("projects/{project_id}")),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("LOCATIONS = {locations:?}");
@@ -125,10 +134,13 @@ async fn run_locations(client: &sm::LocationsClient, project_id: &str) -> Result
 
     println!("\nTesting get_location()");
     let get = client
-        .get_location(loc::model::GetLocationRequest::default().set_name(format!(
-            "projects/{project_id}/locations/{}",
-            first.location_id
-        )))
+        .get_location(
+            loc::model::GetLocationRequest::default().set_name(format!(
+                "projects/{project_id}/locations/{}",
+                first.location_id
+            )),
+            gax::options::RequestOptions::default(),
+        )
         .await?;
     println!("GET = {get:?}");
 
@@ -142,7 +154,10 @@ async fn run_iam(client: &sm::SecretManagerServiceClient, secret_name: &str) ->
 
     println!("\nTesting get_iam_policy()");
     let policy = client
-        .get_iam_policy(iam_v1::model::GetIamPolicyRequest::default().set_resource(secret_name))
+        .get_iam_policy(
+            iam_v1::model::GetIamPolicyRequest::default().set_resource(secret_name),
+            gax::options::RequestOptions::default(),
+        )
         .await?;
     println!("POLICY = {policy:?}");
 
@@ -156,6 +171,7 @@ async fn run_iam(client: &sm::SecretManagerServiceClient, secret_name: &str) ->
                         .map(str::to_string)
                         .to_vec(),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {response:?}");
@@ -189,6 +205,7 @@ async fn run_iam(client: &sm::SecretManagerServiceClient, secret_name: &str) ->
                     wkt::FieldMask::default().set_paths(["bindings"].map(str::to_string).to_vec()),
                 )
                 .set_policy(new_policy),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {response:?}");
@@ -212,6 +229,7 @@ async fn run_secret_versions(
                         .set_data(bytes::Bytes::from(data))
                         .set_data_crc32c(checksum as i64),
                 ),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("CREATE_SECRET_VERSION = {create_secret_version:?}");
@@ -220,6 +238,7 @@ async fn run_secret_versions(
     let get_secret_version = client
         .get_secret_version(
             sm::model::GetSecretVersionRequest::default().set_name(&create_secret_version.name),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("GET_SECRET_VERSION = {create_secret_version:?}");
@@ -239,6 +258,7 @@ async fn run_secret_versions(
     let access_secret_version = client
         .access_secret_version(
             sm::model::AccessSecretVersionRequest::default().set_name(&create_secret_version.name),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("ACCESS_SECRET_VERSION = {access_secret_version:?}");
@@ -251,6 +271,7 @@ async fn run_secret_versions(
     let disable = client
         .disable_secret_version(
             sm::model::DisableSecretVersionRequest::default().set_name(&create_secret_version.name),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("DISABLE_SECRET_VERSION = {disable:?}");
@@ -259,6 +280,7 @@ async fn run_secret_versions(
     let enable = client
         .enable_secret_version(
             sm::model::EnableSecretVersionRequest::default().set_name(&create_secret_version.name),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("ENABLE_SECRET_VERSION = {enable:?}");
@@ -267,6 +289,7 @@ async fn run_secret_versions(
     let delete = client
         .destroy_secret_version(
             sm::model::DestroySecretVersionRequest::default().set_name(&get_secret_version.name),
+            gax::options::RequestOptions::default(),
         )
         .await?;
     println!("RESPONSE = {delete:?}");
@@ -279,23 +302,14 @@ async fn get_all_secret_version_names(
     secret_name: &str,
 ) -> Result<Vec<String>> {
     let mut names = Vec::new();
-    let mut page_token = String::new();
-    loop {
-        let response = client
-            .list_secret_versions(
-                sm::model::ListSecretVersionsRequest::default()
-                    .set_parent(secret_name)
-                    .set_page_token(&page_token),
-            )
-            .await?;
-        response
-            .versions
-            .into_iter()
-            .for_each(|s| names.push(s.name));
-        if response.next_page_token.is_empty() {
-            break;
-        }
-        page_token = response.next_page_token;
+    let mut items = client
+        .list_secret_versions_stream(
+            sm::model::ListSecretVersionsRequest::default().set_parent(secret_name),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(version) = items.next().await {
+        names.push(version?.name);
     }
     Ok(names)
 }
@@ -305,23 +319,14 @@ async fn get_all_secret_names(
     project_id: &str,
 ) -> Result<Vec<String>> {
     let mut names = Vec::new();
-    let mut page_token = String::new();
-    loop {
-        let response = client
-            .list_secrets(
-                sm::model::ListSecretsRequest::default()
-                    .set_parent(format!("projects/{project_id}"))
-                    .set_page_token(&page_token),
-            )
-            .await?;
-        response
-            .secrets
-            .into_iter()
-            .for_each(|s| names.push(s.name));
-        if response.next_page_token.is_empty() {
-            break;
-        }
-        page_token = response.next_page_token;
+    let mut items = client
+        .list_secrets_stream(
+            sm::model::ListSecretsRequest::default().set_parent(format!("projects/{project_id}")),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(secret) = items.next().await {
+        names.push(secret?.name);
     }
     Ok(names)
 }
@@ -339,35 +344,38 @@ async fn cleanup_stale_secrets(
     let stale_deadline = wkt::Timestamp::clamp(stale_deadline.as_secs() as i64, 0);
 
     let mut stale_secrets = Vec::new();
-    let mut list_request =
-        sm::model::ListSecretsRequest::default().set_parent(format!("projects/{project_id}"));
-    loop {
-        let response = client.list_secrets(list_request.clone()).await?;
-        for secret in response.secrets {
-            if secret
-                .name
-                .ends_with(format!("/secrets/{secret_id}").as_str())
-            {
-                return Err(Error::other(
-                    "randomly generated secret id already exists {secret_id}",
-                ));
-            }
+    let mut items = client
+        .list_secrets_stream(
+            sm::model::ListSecretsRequest::default().set_parent(format!("projects/{project_id}")),
+            gax::options::RequestOptions::default(),
+        )
+        .items();
+    while let Some(secret) = items.next().await {
+        let secret = secret?;
+        if secret
+            .name
+            .ends_with(format!("/secrets/{secret_id}").as_str())
+        {
+            return Err(Error::other(
+                "randomly generated secret id already exists {secret_id}",
+            ));
+        }
 
-            if let Some("true") = secret.labels.get("integration-test").map(String::as_str) {
-                if let Some(true) = secret.create_time.map(|v| v < stale_deadline) {
-                    stale_secrets.push(secret.name);
-                }
+        if let Some("true") = secret.labels.get("integration-test").map(String::as_str) {
+            if let Some(true) = secret.create_time.map(|v| v < stale_deadline) {
+                stale_secrets.push(secret.name);
             }
         }
-        if response.next_page_token.is_empty() {
-            break;
-        }
-        list_request.page_token = response.next_page_token;
     }
 
     let pending = stale_secrets
         .iter()
-        .map(|v| client.delete_secret(sm::model::DeleteSecretRequest::default().set_name(v)))
+        .map(|v| {
+            client.delete_secret(
+                sm::model::DeleteSecretRequest::default().set_name(v),
+                gax::options::RequestOptions::default(),
+            )
+        })
         .collect::<Vec<_>>();
 
     // Print the errors, but otherwise ignore them.
@@ -0,0 +1,84 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verify generated clients send the headers of static credentials, using a
+//! local fake of Secret Manager.
+
+mod common;
+
+mod credentials {
+    use axum::extract::Path;
+    use axum::http::HeaderMap;
+    use axum::Json;
+    use sm::traits::SecretManagerService;
+
+    /// Starts a fake returning the secret name and the authentication headers
+    /// of each request in the secret labels.
+    async fn start_fake() -> String {
+        let handler = |Path(name): Path<String>, headers: HeaderMap| async move {
+            let header = |name: &str| {
+                headers
+                    .get(name)
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            Json(serde_json::json!({
+                "name": name,
+                "labels": {
+                    "authorization": header("authorization"),
+                    "x-goog-api-key": header("x-goog-api-key"),
+                    "x-goog-user-project": header("x-goog-user-project"),
+                },
+            }))
+        };
+        let app = axum::Router::new().route("/v1/{*name}", axum::routing::get(handler));
+        super::common::serve(app).await
+    }
+
+    async fn get_secret(cred: auth::Credential) -> sm::Result<sm::model::Secret> {
+        let config = sm::ConfigBuilder::new()
+            .set_endpoint(start_fake().await)
+            .set_credential(cred);
+        let client = sm::SecretManagerServiceClient::new_with_config(config).await?;
+        client
+            .get_secret(
+                sm::model::GetSecretRequest::default()
+                    .set_name("projects/test-only/secrets/test-only"),
+                gax::options::RequestOptions::default(),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn access_token() -> sm::Result<()> {
+        let cred = auth::Credential::from_access_token("test-only-token", None)
+            .with_quota_project_id("test-only-project");
+        let secret = get_secret(cred).await?;
+        assert_eq!(secret.name, "projects/test-only/secrets/test-only");
+        assert_eq!(secret.labels["authorization"], "Bearer test-only-token");
+        assert_eq!(secret.labels["x-goog-api-key"], "");
+        assert_eq!(secret.labels["x-goog-user-project"], "test-only-project");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn api_key() -> sm::Result<()> {
+        let cred = auth::Credential::from_api_key("test-only-key");
+        let secret = get_secret(cred).await?;
+        assert_eq!(secret.labels["authorization"], "");
+        assert_eq!(secret.labels["x-goog-api-key"], "test-only-key");
+        Ok(())
+    }
+}
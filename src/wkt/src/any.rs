@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::Message;
+
 /// `Any` contains an arbitrary serialized protocol buffer message along with a
 /// URL that describes the type of the serialized message.
 ///
@@ -60,71 +62,191 @@ pub enum AnyError {
     SerializationError(#[source] Box<dyn std::error::Error>),
     #[error("cannot deserialize from an Any, source={0:?}")]
     DeserializationError(#[source] Box<dyn std::error::Error>),
-    #[error("expected type mismatch in Any deserialization type={0}")]
-    TypeMismatchError(String),
+    #[error("type mismatch in Any deserialization, got={got}, want={want}")]
+    TypeMismatchError { got: String, want: String },
+    #[error("no message type registered for type URL {0}")]
+    UnknownTypeError(String),
 }
 
 type Error = AnyError;
 
+/// The prefix used in type URLs for messages packed by this crate.
+const TYPE_URL_PREFIX: &str = "type.googleapis.com/";
+
 impl Any {
-    // TODO(#98) - each message should have a type value
-    pub fn from<T>(message: &T) -> Result<Self, Error>
+    /// Packs a message into an `Any`.
+    ///
+    /// The type URL is `type.googleapis.com/` followed by the fully qualified
+    /// name of the message type.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_wkt::{Any, Duration};
+    /// let any = Any::from_msg(&Duration::clamp(60, 0))?;
+    /// assert_eq!(
+    ///     any.type_url(),
+    ///     Some("type.googleapis.com/google.protobuf.Duration")
+    /// );
+    /// # Ok::<(), gcp_sdk_wkt::AnyError>(())
+    /// ```
+    pub fn from_msg<T>(message: &T) -> Result<Self, Error>
     where
-        T: serde::ser::Serialize,
+        T: Message,
     {
-        use serde_json::{json, Value};
-
-        let value =
-            serde_json::to_value(message).map_err(|e| Error::SerializationError(e.into()))?;
-        let value = match value {
-            Value::Object(mut map) => {
-                map.insert("@type".to_string(), serde_json::json!(""));
-                Ok(Value::Object(map))
-            }
-            Value::String(s) => {
-                // Only a handful of well-known messages are serialized into
-                // something other than a object. In all cases, they are
-                // serialized using a `value` field.
-                // TODO(#98) - each message should have a type value
-                Ok(json!({"@type": "type.googleapis.com/google.protobuf.", "value": s}))
-            }
-            _ => Err(Error::SerializationError(Box::from(
-                "unexpected JSON type, only Object and String are supported",
-            ))),
-        }?;
-        Ok(Any(value))
+        let mut map = message.to_map()?;
+        map.insert(
+            "@type".to_string(),
+            serde_json::Value::String(format!("{TYPE_URL_PREFIX}{}", T::typename())),
+        );
+        Ok(Any(serde_json::Value::Object(map)))
+    }
+
+    /// Unpacks the message contained in an `Any`.
+    ///
+    /// Returns [AnyError::TypeMismatchError] if the `Any` contains a different
+    /// message type.
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_wkt::{Any, AnyError, Duration, Timestamp};
+    /// let any = Any::from_msg(&Duration::clamp(60, 0))?;
+    /// assert_eq!(any.to_msg::<Duration>()?, Duration::clamp(60, 0));
+    /// assert!(matches!(
+    ///     any.to_msg::<Timestamp>(),
+    ///     Err(AnyError::TypeMismatchError { .. })
+    /// ));
+    /// # Ok::<(), AnyError>(())
+    /// ```
+    pub fn to_msg<T>(&self) -> Result<T, Error>
+    where
+        T: Message,
+    {
+        let map = self.object()?;
+        let got = Self::type_name(map)?;
+        if got != T::typename() {
+            return Err(Error::TypeMismatchError {
+                got: got.to_string(),
+                want: T::typename().to_string(),
+            });
+        }
+        T::from_map(map)
     }
 
-    fn map_de_err(e: Box<dyn std::error::Error>) -> Error {
-        Error::DeserializationError(e)
+    /// Returns the type URL, if present.
+    pub fn type_url(&self) -> Option<&str> {
+        self.0.get("@type").and_then(serde_json::Value::as_str)
     }
 
-    fn map_de_str(s: String) -> Error {
-        Error::DeserializationError(Box::from(s))
+    fn object(&self) -> Result<&serde_json::Map<String, serde_json::Value>, Error> {
+        self.0.as_object().ok_or_else(|| {
+            Error::DeserializationError(Box::from("expected Object value inside Any"))
+        })
     }
 
-    pub fn try_into_message<T>(&self) -> Result<T, Error>
+    /// Extracts the fully qualified name of the message type from the type
+    /// URL. Only the last segment of the URL is significant.
+    fn type_name(map: &serde_json::Map<String, serde_json::Value>) -> Result<&str, Error> {
+        let url = map
+            .get("@type")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| {
+                Error::DeserializationError(Box::from("@type field is missing or is not a string"))
+            })?;
+        Ok(url.rsplit_once('/').map(|(_, name)| name).unwrap_or(url))
+    }
+}
+
+type Decoder = fn(
+    &serde_json::Map<String, serde_json::Value>,
+) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>;
+
+/// A registry of message types, used to unpack [Any] values whose type is not
+/// known at compile time.
+///
+/// Applications register the message types they expect, and then call
+/// [TypeRegistry::decode] to unpack any of these types. The result can be
+/// inspected using [downcast](Box::downcast).
+///
+/// # Example
+/// ```
+/// # use gcp_sdk_wkt::{Any, Duration, Timestamp, TypeRegistry};
+/// let registry = TypeRegistry::new()
+///     .register::<Duration>()
+///     .register::<Timestamp>();
+/// let any = Any::from_msg(&Duration::clamp(60, 0))?;
+/// let decoded = registry.decode(&any)?;
+/// let duration = decoded.downcast::<Duration>().unwrap();
+/// assert_eq!(*duration, Duration::clamp(60, 0));
+/// # Ok::<(), gcp_sdk_wkt::AnyError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TypeRegistry {
+    decoders: std::collections::HashMap<&'static str, Decoder>,
+}
+
+impl TypeRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry containing all the well-known message types.
+    pub fn with_well_known_types() -> Self {
+        Self::new()
+            .register::<crate::Any>()
+            .register::<crate::Duration>()
+            .register::<crate::Empty>()
+            .register::<crate::FieldMask>()
+            .register::<crate::Timestamp>()
+            .register::<crate::Struct>()
+            .register::<crate::Value>()
+            .register::<crate::ListValue>()
+            .register::<crate::DoubleValue>()
+            .register::<crate::FloatValue>()
+            .register::<crate::Int64Value>()
+            .register::<crate::UInt64Value>()
+            .register::<crate::Int32Value>()
+            .register::<crate::UInt32Value>()
+            .register::<crate::BoolValue>()
+            .register::<crate::StringValue>()
+            .register::<crate::BytesValue>()
+    }
+
+    /// Adds a message type to the registry.
+    pub fn register<T>(mut self) -> Self
     where
-        T: serde::de::DeserializeOwned,
+        T: Message + Send + Sync + 'static,
     {
-        let value = &self.0;
-        let object = value
-            .as_object()
-            .ok_or_else(|| "expected Object value inside Any".to_string())
-            .map_err(Self::map_de_str)?;
-        let r#type = object["@type"]
-            .as_str()
-            .ok_or_else(|| "@type field is missing or is not a string".to_string())
-            .map_err(Self::map_de_str)?;
-        if r#type.starts_with("type.googleapis.com/google.protobuf.") {
-            let value = &object["value"];
-            let t = serde_json::from_value::<T>(value.clone())
-                .map_err(|e| Self::map_de_err(e.into()))?;
-            return Ok(t);
+        fn decode<T>(
+            map: &serde_json::Map<String, serde_json::Value>,
+        ) -> Result<Box<dyn std::any::Any + Send + Sync>, Error>
+        where
+            T: Message + Send + Sync + 'static,
+        {
+            T::from_map(map).map(|m| Box::new(m) as Box<dyn std::any::Any + Send + Sync>)
         }
-        let t =
-            serde_json::from_value::<T>(value.clone()).map_err(|e| Self::map_de_err(e.into()))?;
-        Ok(t)
+        self.decoders.insert(T::typename(), decode::<T>);
+        self
+    }
+
+    /// Returns true if a message type with the given fully qualified name is
+    /// registered.
+    pub fn contains(&self, typename: &str) -> bool {
+        self.decoders.contains_key(typename)
+    }
+
+    /// Unpacks the message contained in an [Any].
+    ///
+    /// Returns [AnyError::UnknownTypeError] if the message type is not
+    /// registered.
+    pub fn decode(&self, any: &Any) -> Result<Box<dyn std::any::Any + Send + Sync>, Error> {
+        let map = any.object()?;
+        let name = Any::type_name(map)?;
+        let decoder = self
+            .decoders
+            .get(name)
+            .ok_or_else(|| Error::UnknownTypeError(any.type_url().unwrap_or(name).to_string()))?;
+        decoder(map)
     }
 }
 
@@ -165,21 +287,28 @@ mod test {
         pub id: String,
     }
 
+    impl Message for Stored {
+        fn typename() -> &'static str {
+            "wkt.test.Stored"
+        }
+    }
+
     #[test]
     fn serialize_duration() -> Result {
         let d = Duration::clamp(60, 0);
-        let any = Any::from(&d)?;
+        let any = Any::from_msg(&d)?;
         let got = serde_json::to_value(any)?;
-        let want = json!({"@type": "type.googleapis.com/google.protobuf.", "value": "60s"});
+        let want = json!({"@type": "type.googleapis.com/google.protobuf.Duration", "value": "60s"});
         assert_eq!(got, want);
         Ok(())
     }
 
     #[test]
     fn deserialize_duration() -> Result {
-        let input = json!({"@type": "type.googleapis.com/google.protobuf.", "value": "60s"});
+        let input =
+            json!({"@type": "type.googleapis.com/google.protobuf.Duration", "value": "60s"});
         let any = Any(input);
-        let d = any.try_into_message::<Duration>()?;
+        let d = any.to_msg::<Duration>()?;
         assert_eq!(d, Duration::clamp(60, 0));
         Ok(())
     }
@@ -190,18 +319,20 @@ mod test {
             parent: "parent".to_string(),
             id: "id".to_string(),
         };
-        let any = Any::from(&d)?;
+        let any = Any::from_msg(&d)?;
         let got = serde_json::to_value(any)?;
-        let want = json!({"@type": "", "parent": "parent", "id": "id"});
+        let want =
+            json!({"@type": "type.googleapis.com/wkt.test.Stored", "parent": "parent", "id": "id"});
         assert_eq!(got, want);
         Ok(())
     }
 
     #[test]
     fn deserialize_generic() -> Result {
-        let input = json!({"@type": "", "parent": "parent", "id": "id"});
+        let input =
+            json!({"@type": "type.googleapis.com/wkt.test.Stored", "parent": "parent", "id": "id"});
         let any = Any(input);
-        let d = any.try_into_message::<Stored>()?;
+        let d = any.to_msg::<Stored>()?;
         assert_eq!(
             d,
             Stored {
@@ -211,4 +342,94 @@ mod test {
         );
         Ok(())
     }
+
+    #[test]
+    fn serialize_any_in_any() -> Result {
+        let inner = Any::from_msg(&Duration::clamp(60, 0))?;
+        let any = Any::from_msg(&inner)?;
+        let got = serde_json::to_value(&any)?;
+        let want = json!({
+            "@type": "type.googleapis.com/google.protobuf.Any",
+            "value": {"@type": "type.googleapis.com/google.protobuf.Duration", "value": "60s"},
+        });
+        assert_eq!(got, want);
+        let roundtrip = any.to_msg::<Any>()?.to_msg::<Duration>()?;
+        assert_eq!(roundtrip, Duration::clamp(60, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn type_url_prefix_ignored() -> Result {
+        let input =
+            json!({"@type": "example.com/custom/prefix/google.protobuf.Duration", "value": "60s"});
+        let any = Any(input);
+        assert_eq!(any.to_msg::<Duration>()?, Duration::clamp(60, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn type_mismatch() -> Result {
+        let any = Any::from_msg(&Duration::clamp(60, 0))?;
+        let got = any.to_msg::<Stored>();
+        match got {
+            Err(Error::TypeMismatchError { got, want }) => {
+                assert_eq!(got, "google.protobuf.Duration");
+                assert_eq!(want, "wkt.test.Stored");
+            }
+            _ => panic!("expected a TypeMismatchError, got={got:?}"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_errors() {
+        let got = Any(json!("not an object")).to_msg::<Duration>();
+        assert!(
+            matches!(got, Err(Error::DeserializationError(_))),
+            "{got:?}"
+        );
+        let got = Any(json!({"value": "60s"})).to_msg::<Duration>();
+        assert!(
+            matches!(got, Err(Error::DeserializationError(_))),
+            "{got:?}"
+        );
+        assert_eq!(Any::default().type_url(), None);
+    }
+
+    #[test]
+    fn registry() -> Result {
+        let registry = TypeRegistry::with_well_known_types().register::<Stored>();
+        assert!(registry.contains("google.protobuf.Duration"));
+        assert!(registry.contains("wkt.test.Stored"));
+
+        let any = Any::from_msg(&Duration::clamp(60, 0))?;
+        let got = registry.decode(&any)?;
+        assert_eq!(
+            got.downcast_ref::<Duration>(),
+            Some(&Duration::clamp(60, 0))
+        );
+
+        let input = Stored {
+            parent: "parent".to_string(),
+            id: "id".to_string(),
+        };
+        let any = Any::from_msg(&input)?;
+        let got = registry.decode(&any)?;
+        assert_eq!(got.downcast_ref::<Stored>(), Some(&input));
+        Ok(())
+    }
+
+    #[test]
+    fn registry_unknown_type() -> Result {
+        let registry = TypeRegistry::new().register::<Duration>();
+        let any = Any::from_msg(&crate::Empty::default())?;
+        let got = registry.decode(&any);
+        match got {
+            Err(Error::UnknownTypeError(url)) => {
+                assert_eq!(url, "type.googleapis.com/google.protobuf.Empty");
+            }
+            _ => panic!("expected an UnknownTypeError, got={:?}", got.map(|_| ())),
+        }
+        Ok(())
+    }
 }
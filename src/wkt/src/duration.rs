@@ -31,7 +31,7 @@
 /// encoded in JSON format as "3s", while 3 seconds and 1 nanosecond should
 /// be expressed in JSON format as "3.000000001s", and 3 seconds and 1
 /// microsecond should be expressed in JSON format as "3.000001s".
-#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 pub struct Duration {
     /// Signed seconds of the span of time.
@@ -137,6 +137,142 @@ impl Duration {
     pub fn nanos(&self) -> i32 {
         self.nanos
     }
+
+    /// Returns true if the duration is negative.
+    pub fn is_negative(&self) -> bool {
+        self.seconds < 0 || self.nanos < 0
+    }
+
+    /// Computes `self + rhs`, returning `None` if the result is out of range.
+    pub fn checked_add(&self, rhs: &Duration) -> Option<Duration> {
+        Self::from_nanos(self.as_nanos() + rhs.as_nanos()).ok()
+    }
+
+    /// Computes `self - rhs`, returning `None` if the result is out of range.
+    pub fn checked_sub(&self, rhs: &Duration) -> Option<Duration> {
+        Self::from_nanos(self.as_nanos() - rhs.as_nanos()).ok()
+    }
+
+    /// Computes `self * rhs`, returning `None` if the result is out of range.
+    pub fn checked_mul(&self, rhs: i32) -> Option<Duration> {
+        Self::from_nanos(self.as_nanos() * rhs as i128).ok()
+    }
+
+    /// Computes `self / rhs`, returning `None` if `rhs == 0`.
+    ///
+    /// The result is truncated towards zero.
+    pub fn checked_div(&self, rhs: i32) -> Option<Duration> {
+        if rhs == 0 {
+            return None;
+        }
+        Self::from_nanos(self.as_nanos() / rhs as i128).ok()
+    }
+
+    /// Computes `self + rhs`, clamping the result to the valid range.
+    pub fn saturating_add(&self, rhs: &Duration) -> Duration {
+        Self::from_nanos_saturating(self.as_nanos() + rhs.as_nanos())
+    }
+
+    /// Computes `self - rhs`, clamping the result to the valid range.
+    pub fn saturating_sub(&self, rhs: &Duration) -> Duration {
+        Self::from_nanos_saturating(self.as_nanos() - rhs.as_nanos())
+    }
+
+    /// Returns the absolute value of the duration.
+    ///
+    /// The range of [Duration] is symmetric, so this cannot fail.
+    pub fn abs(&self) -> Duration {
+        Self {
+            seconds: self.seconds.abs(),
+            nanos: self.nanos.abs(),
+        }
+    }
+
+    /// The total number of nanoseconds in the duration.
+    pub(crate) fn as_nanos(&self) -> i128 {
+        self.seconds as i128 * Self::NS as i128 + self.nanos as i128
+    }
+
+    /// Creates a [Duration] from a total number of nanoseconds.
+    pub(crate) fn from_nanos(nanos: i128) -> std::result::Result<Self, Error> {
+        let ns = Self::NS as i128;
+        let seconds = nanos / ns;
+        if !(Self::MIN_SECONDS as i128..=Self::MAX_SECONDS as i128).contains(&seconds) {
+            return Err(Error::OutOfRange());
+        }
+        Self::new(seconds as i64, (nanos % ns) as i32)
+    }
+
+    fn from_nanos_saturating(nanos: i128) -> Self {
+        let saturated = if nanos < 0 {
+            Self {
+                seconds: Self::MIN_SECONDS,
+                nanos: Self::MIN_NANOS,
+            }
+        } else {
+            Self {
+                seconds: Self::MAX_SECONDS,
+                nanos: Self::MAX_NANOS,
+            }
+        };
+        Self::from_nanos(nanos).unwrap_or(saturated)
+    }
+}
+
+/// Adds two [Duration] values.
+///
+/// # Panics
+///
+/// Panics if the result is out of range, use [Duration::checked_add] to handle
+/// this case.
+impl std::ops::Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(&rhs)
+            .expect("overflow when adding durations")
+    }
+}
+
+/// Subtracts two [Duration] values.
+///
+/// # Panics
+///
+/// Panics if the result is out of range, use [Duration::checked_sub] to handle
+/// this case.
+impl std::ops::Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(&rhs)
+            .expect("overflow when subtracting durations")
+    }
+}
+
+impl std::ops::AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = std::mem::take(self) + rhs;
+    }
+}
+
+impl std::ops::SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = std::mem::take(self) - rhs;
+    }
+}
+
+/// Negates a [Duration].
+///
+/// The range of [Duration] is symmetric, so this cannot fail.
+impl std::ops::Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            seconds: -self.seconds,
+            nanos: -self.nanos,
+        }
+    }
 }
 
 impl std::convert::TryFrom<std::time::Duration> for Duration {
@@ -190,6 +326,29 @@ impl std::convert::From<Duration> for time::Duration {
     }
 }
 
+/// Convert from [chrono::Duration] to [Duration].
+///
+/// This conversion may fail if the [chrono::Duration] value is out of range.
+#[cfg(feature = "chrono")]
+impl std::convert::TryFrom<chrono::Duration> for Duration {
+    type Error = DurationError;
+
+    fn try_from(value: chrono::Duration) -> std::result::Result<Self, Self::Error> {
+        Self::new(value.num_seconds(), value.subsec_nanos())
+    }
+}
+
+/// Convert from [Duration] to [chrono::Duration].
+///
+/// This conversion is always safe because the range for [Duration] is
+/// guaranteed to fit into the destination type.
+#[cfg(feature = "chrono")]
+impl std::convert::From<Duration> for chrono::Duration {
+    fn from(value: Duration) -> Self {
+        Self::seconds(value.seconds()) + Self::nanoseconds(value.nanos() as i64)
+    }
+}
+
 /// Implement [`serde`](::serde) serialization for [Duration].
 impl serde::ser::Serialize for Duration {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -412,4 +571,125 @@ mod test {
         assert_eq!(got, want);
         Ok(())
     }
+
+    #[test_case(Duration::clamp(1, 500_000_000), Duration::clamp(2, 600_000_000), Duration::clamp(4, 100_000_000) ; "carry")]
+    #[test_case(Duration::clamp(1, 0), Duration::clamp(-2, -500_000_000), Duration::clamp(-1, -500_000_000) ; "mixed signs")]
+    #[test_case(Duration::clamp(0, -300), Duration::clamp(0, 100), Duration::clamp(0, -200) ; "only nanos")]
+    #[test_case(Duration::clamp(Duration::MAX_SECONDS, 0), Duration::clamp(0, 999_999_999), Duration::new(Duration::MAX_SECONDS, 999_999_999).unwrap() ; "max")]
+    fn add(lhs: Duration, rhs: Duration, want: Duration) {
+        assert_eq!(lhs.clone() + rhs.clone(), want);
+        assert_eq!(rhs.clone() + lhs.clone(), want);
+        assert_eq!(lhs.checked_add(&rhs), Some(want.clone()));
+        assert_eq!(want.clone() - rhs.clone(), lhs);
+        assert_eq!(want.checked_sub(&lhs), Some(rhs.clone()));
+        let mut got = lhs.clone();
+        got += rhs.clone();
+        assert_eq!(got, want);
+        got -= rhs;
+        assert_eq!(got, lhs);
+    }
+
+    #[test]
+    fn checked_overflow() {
+        let max = Duration::new(Duration::MAX_SECONDS, Duration::MAX_NANOS).unwrap();
+        let min = -max.clone();
+        let one = Duration::clamp(0, 1);
+        assert_eq!(max.checked_add(&one), None);
+        assert_eq!(min.checked_sub(&one), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(max.saturating_add(&one), max);
+        assert_eq!(min.saturating_sub(&one), min);
+        assert_eq!(max.saturating_add(&max), max);
+        assert_eq!(min.saturating_sub(&max), min);
+        assert_eq!(
+            max.saturating_sub(&one),
+            Duration::clamp(Duration::MAX_SECONDS, 999_999_998)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow() {
+        let max = Duration::clamp(Duration::MAX_SECONDS, 0);
+        let _ = max.clone() + max;
+    }
+
+    #[test]
+    fn mul_div() {
+        let d = Duration::clamp(1, 500_000_000);
+        assert_eq!(d.checked_mul(3), Some(Duration::clamp(4, 500_000_000)));
+        assert_eq!(d.checked_mul(-2), Some(Duration::clamp(-3, 0)));
+        assert_eq!(d.checked_div(2), Some(Duration::clamp(0, 750_000_000)));
+        assert_eq!(d.checked_div(-4), Some(Duration::clamp(0, -375_000_000)));
+    }
+
+    #[test]
+    fn neg_abs() {
+        let d = Duration::clamp(1, 500_000_000);
+        assert_eq!(-d.clone(), Duration::clamp(-1, -500_000_000));
+        assert_eq!((-d.clone()).abs(), d);
+        assert!((-d.clone()).is_negative());
+        assert!(!d.is_negative());
+        assert!(!Duration::default().is_negative());
+    }
+
+    #[test_case(Duration::clamp(-1, 0), Duration::clamp(0, -1))]
+    #[test_case(Duration::clamp(0, -1), Duration::default())]
+    #[test_case(Duration::default(), Duration::clamp(0, 1))]
+    #[test_case(Duration::clamp(0, 999_999_999), Duration::clamp(1, 0))]
+    #[test_case(Duration::clamp(1, 0), Duration::clamp(1, 1))]
+    fn ordering(lower: Duration, higher: Duration) {
+        assert!(lower < higher, "{lower:?} < {higher:?}");
+        assert_eq!(lower.cmp(&higher), std::cmp::Ordering::Less);
+        assert_eq!(lower.max(higher.clone()), higher);
+    }
+
+    #[test_case(Duration::MIN_SECONDS, 0 ; "min")]
+    #[test_case(Duration::MIN_SECONDS + 1, Duration::MIN_NANOS ; "min with nanos")]
+    #[test_case(Duration::MAX_SECONDS, 0 ; "max")]
+    #[test_case(Duration::MAX_SECONDS - 1, Duration::MAX_NANOS ; "max with nanos")]
+    #[test_case(0, 0 ; "zero")]
+    #[test_case(0, -1 ; "negative nanos only")]
+    fn chrono_roundtrip(seconds: i64, nanos: i32) -> Result {
+        let d = Duration::new(seconds, nanos)?;
+        let c = chrono::Duration::from(d.clone());
+        assert_eq!(c.num_seconds(), seconds);
+        assert_eq!(c.subsec_nanos(), nanos);
+        assert_eq!(Duration::try_from(c)?, d);
+        Ok(())
+    }
+
+    #[test_case(chrono::Duration::seconds(Duration::MAX_SECONDS + 1) ; "above range")]
+    #[test_case(chrono::Duration::seconds(Duration::MIN_SECONDS - 1) ; "below range")]
+    #[test_case(chrono::Duration::milliseconds(i64::MAX) ; "chrono max")]
+    #[test_case(chrono::Duration::milliseconds(-i64::MAX) ; "chrono min")]
+    fn chrono_out_of_range(value: chrono::Duration) {
+        let got = Duration::try_from(value);
+        assert_eq!(got, Err(Error::OutOfRange()));
+    }
+
+    fn duration_parts() -> impl proptest::strategy::Strategy<Value = (i64, i32)> {
+        use proptest::prelude::*;
+        let seconds = prop_oneof![
+            Duration::MIN_SECONDS..=Duration::MIN_SECONDS + 1_000,
+            Duration::MAX_SECONDS - 1_000..=Duration::MAX_SECONDS,
+            -1_000_i64..=1_000,
+            Duration::MIN_SECONDS..=Duration::MAX_SECONDS,
+        ];
+        let nanos = prop_oneof![Just(0), Just(Duration::MAX_NANOS), 0..=Duration::MAX_NANOS,];
+        // The nanos must have the same sign as the seconds.
+        (seconds, nanos).prop_map(|(s, n)| if s < 0 { (s, -n) } else { (s, n) })
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn chrono_roundtrip_property((seconds, nanos) in duration_parts()) {
+            let d = Duration::new(seconds, nanos).unwrap();
+            let c = chrono::Duration::from(d.clone());
+            proptest::prop_assert_eq!(c.num_seconds(), seconds);
+            proptest::prop_assert_eq!(c.subsec_nanos(), nanos);
+            proptest::prop_assert_eq!(Duration::try_from(c).unwrap(), d);
+        }
+    }
 }
@@ -237,11 +237,14 @@
 /// The implementation of any API method which has a FieldMask type field in the
 /// request should verify the included field paths, and return an
 /// `INVALID_ARGUMENT` error if any path is unmappable.
-#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize)]
+#[derive(Clone, Debug, Default, PartialEq)]
 #[non_exhaustive]
 pub struct FieldMask {
     /// The set of field mask paths.
-    #[serde(deserialize_with = "crate::field_mask::deserialize_paths")]
+    ///
+    /// The paths use the Protobuf field names, that is, `snake_case`. They are
+    /// converted to and from the JSON names (`lowerCamelCase`) when the field
+    /// mask is serialized or deserialized.
     pub paths: Vec<String>,
 }
 
@@ -251,35 +254,163 @@ impl FieldMask {
         self.paths = paths;
         self
     }
+
+    /// Returns the canonical form of the field mask.
+    ///
+    /// In the canonical form the paths are sorted and deduplicated, and any
+    /// path covered by another path is removed. For example, `a.b` is removed
+    /// if `a` is also in the mask.
+    pub fn normalize(mut self) -> Self {
+        self.paths.retain(|p| !p.is_empty());
+        self.paths.sort();
+        self.paths.dedup();
+        // After sorting, any paths covered by `a` immediately follow `a`.
+        let mut paths: Vec<String> = Vec::with_capacity(self.paths.len());
+        for path in self.paths {
+            if paths.last().is_some_and(|prev| covers(prev, &path)) {
+                continue;
+            }
+            paths.push(path);
+        }
+        Self { paths }
+    }
+
+    /// Returns the canonical form of the union of two field masks.
+    pub fn union(&self, other: &FieldMask) -> Self {
+        let paths = self.paths.iter().chain(other.paths.iter()).cloned();
+        Self {
+            paths: paths.collect(),
+        }
+        .normalize()
+    }
+
+    /// Returns the canonical form of the intersection of two field masks.
+    ///
+    /// A path is in the intersection if it is covered by both masks. For
+    /// example, the intersection of `a` and `a.b,c` is `a.b`.
+    pub fn intersection(&self, other: &FieldMask) -> Self {
+        let mut paths = Vec::new();
+        for p in &self.paths {
+            for q in &other.paths {
+                if covers(p, q) {
+                    paths.push(q.clone());
+                } else if covers(q, p) {
+                    paths.push(p.clone());
+                }
+            }
+        }
+        Self { paths }.normalize()
+    }
+
+    /// Returns true if `path` is covered by the field mask.
+    ///
+    /// A path is covered if it is in the mask, or if any of its parent paths
+    /// is in the mask. For example, `a.b.c` is covered by a mask with `a.b`.
+    /// Note that an empty mask does not cover any paths.
+    pub fn contains(&self, path: &str) -> bool {
+        self.paths.iter().any(|p| covers(p, path))
+    }
+
+    /// Converts a path using the Protobuf field names (`snake_case`) to a path
+    /// using the JSON field names (`lowerCamelCase`).
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_wkt::FieldMask;
+    /// assert_eq!(FieldMask::to_json_path("user.display_name"), "user.displayName");
+    /// ```
+    pub fn to_json_path(path: &str) -> String {
+        let mut result = String::with_capacity(path.len());
+        let mut upper = false;
+        for c in path.chars() {
+            if c == '_' {
+                upper = true;
+            } else if upper {
+                result.push(c.to_ascii_uppercase());
+                upper = false;
+            } else {
+                result.push(c);
+            }
+        }
+        result
+    }
+
+    /// Converts a path using the JSON field names (`lowerCamelCase`) to a path
+    /// using the Protobuf field names (`snake_case`).
+    ///
+    /// # Example
+    /// ```
+    /// # use gcp_sdk_wkt::FieldMask;
+    /// assert_eq!(FieldMask::from_json_path("user.displayName"), "user.display_name");
+    /// ```
+    pub fn from_json_path(path: &str) -> String {
+        let mut result = String::with_capacity(path.len() + 4);
+        for c in path.chars() {
+            if c.is_ascii_uppercase() {
+                result.push('_');
+                result.push(c.to_ascii_lowercase());
+            } else {
+                result.push(c);
+            }
+        }
+        result
+    }
+}
+
+/// Returns true if `path` is `prefix`, or a sub-field of `prefix`.
+fn covers(prefix: &str, path: &str) -> bool {
+    match path.strip_prefix(prefix) {
+        None => false,
+        Some(rest) => rest.is_empty() || rest.starts_with('.'),
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for FieldMask {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self {
+            paths: iter.into_iter().map(Into::into).collect(),
+        }
+    }
 }
 
 /// Implement [`serde`](::serde) serialization for [FieldMask]
+///
+/// Following the ProtoJSON mapping, the field mask is serialized as a single
+/// string, with the paths (in JSON names) separated by commas.
 impl serde::ser::Serialize for FieldMask {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::ser::Serializer,
     {
-        use serde::ser::SerializeStruct;
-        let mut state = serializer.serialize_struct("FieldMask", 1)?;
-        state.serialize_field("paths", &self.paths.join(","))?;
-        state.end()
+        let paths = self
+            .paths
+            .iter()
+            .map(|p| Self::to_json_path(p))
+            .collect::<Vec<_>>();
+        serializer.serialize_str(&paths.join(","))
     }
 }
 
-struct PathVisitor;
-
-fn deserialize_paths<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
-where
-    D: serde::de::Deserializer<'de>,
-{
-    deserializer.deserialize_str(PathVisitor)
+/// Implement [`serde`](::serde) deserialization for [FieldMask]
+///
+/// In addition to the ProtoJSON representation, this accepts an object with a
+/// `paths` string field.
+impl<'de> serde::de::Deserialize<'de> for FieldMask {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(FieldMaskVisitor)
+    }
 }
 
-impl serde::de::Visitor<'_> for PathVisitor {
-    type Value = Vec<String>;
+struct FieldMaskVisitor;
+
+impl<'de> serde::de::Visitor<'de> for FieldMaskVisitor {
+    type Value = FieldMask;
 
     fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-        formatter.write_str("a string with comma-separated field mask paths)")
+        formatter.write_str("a string with comma-separated field mask paths")
     }
 
     fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
@@ -287,10 +418,24 @@ impl serde::de::Visitor<'_> for PathVisitor {
         E: serde::de::Error,
     {
         if value.is_empty() {
-            Ok(Vec::new())
-        } else {
-            Ok(value.split(',').map(str::to_string).collect())
+            return Ok(FieldMask::default());
+        }
+        Ok(value.split(',').map(FieldMask::from_json_path).collect())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut mask = FieldMask::default();
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "paths" {
+                mask = FieldMaskVisitor.visit_str(&map.next_value::<String>()?)?;
+            } else {
+                map.next_value::<serde::de::IgnoredAny>()?;
+            }
         }
+        Ok(mask)
     }
 }
 
@@ -305,15 +450,12 @@ mod test {
     #[test_case(vec![], ""; "Serialize empty")]
     #[test_case(vec!["field1"], "field1"; "Serialize single")]
     #[test_case(vec!["field1", "field2", "field3"], "field1,field2,field3"; "Serialize multiple")]
+    #[test_case(vec!["display_name", "user.phone_number"], "displayName,user.phoneNumber"; "Serialize JSON names")]
     fn test_serialize(paths: Vec<&str>, want: &str) -> Result {
         let value = serde_json::to_value(FieldMask {
             paths: paths.into_iter().map(str::to_string).collect(),
         })?;
-        let got = value
-            .get("paths")
-            .ok_or("missing paths")?
-            .as_str()
-            .ok_or("paths is not str")?;
+        let got = value.as_str().ok_or("FieldMask is not str")?;
         assert_eq!(want, got);
         Ok(())
     }
@@ -329,4 +471,79 @@ mod test {
         assert_eq!(got.paths, want);
         Ok(())
     }
+
+    #[test_case("", vec![]; "Deserialize empty")]
+    #[test_case("field1", vec!["field1"]; "Deserialize single")]
+    #[test_case("displayName,user.phoneNumber", vec!["display_name", "user.phone_number"]; "Deserialize JSON names")]
+    fn test_deserialize_string(paths: &str, want: Vec<&str>) -> Result {
+        let value = json!(paths);
+        let got = serde_json::from_value::<FieldMask>(value)?;
+        assert_eq!(got.paths, want);
+        Ok(())
+    }
+
+    #[test_case(json!(1); "number")]
+    #[test_case(json!({"paths": 1}); "paths is number")]
+    fn test_deserialize_error(value: serde_json::Value) {
+        let got = serde_json::from_value::<FieldMask>(value);
+        assert!(got.is_err(), "{got:?}");
+    }
+
+    #[test_case("", ""; "empty")]
+    #[test_case("name", "name"; "single word")]
+    #[test_case("display_name", "displayName"; "snake case")]
+    #[test_case("user.display_name.first_value", "user.displayName.firstValue"; "nested")]
+    #[test_case("labels", "labels"; "labels")]
+    fn json_names(proto: &str, json: &str) {
+        assert_eq!(FieldMask::to_json_path(proto), json);
+        assert_eq!(FieldMask::from_json_path(json), proto);
+        // The conversions are idempotent.
+        assert_eq!(FieldMask::to_json_path(json), json);
+        assert_eq!(FieldMask::from_json_path(proto), proto);
+    }
+
+    fn mask(paths: &[&str]) -> FieldMask {
+        paths.iter().copied().collect()
+    }
+
+    #[test_case(&[], &[]; "empty")]
+    #[test_case(&["b", "a", "b"], &["a", "b"]; "sorted and deduplicated")]
+    #[test_case(&["a.b", "a", "a.c.d"], &["a"]; "subsumed")]
+    #[test_case(&["a.b", "a.bc", "a.b.c"], &["a.b", "a.bc"]; "common prefix is not parent")]
+    #[test_case(&["ab", "a", "a.b"], &["a", "ab"]; "sibling with common prefix")]
+    #[test_case(&["", "a"], &["a"]; "empty path removed")]
+    fn normalize(input: &[&str], want: &[&str]) {
+        assert_eq!(mask(input).normalize(), mask(want));
+    }
+
+    #[test_case(&["a"], &["b"], &["a", "b"]; "disjoint")]
+    #[test_case(&["a.b"], &["a"], &["a"]; "subsumed")]
+    #[test_case(&["a.b", "c"], &["a.c", "c.d"], &["a.b", "a.c", "c"]; "mixed")]
+    #[test_case(&[], &["a"], &["a"]; "empty")]
+    fn union(lhs: &[&str], rhs: &[&str], want: &[&str]) {
+        assert_eq!(mask(lhs).union(&mask(rhs)), mask(want));
+        assert_eq!(mask(rhs).union(&mask(lhs)), mask(want));
+    }
+
+    #[test_case(&["a"], &["b"], &[]; "disjoint")]
+    #[test_case(&["a"], &["a.b", "c"], &["a.b"]; "nested")]
+    #[test_case(&["a.b", "c"], &["a", "c.d", "e"], &["a.b", "c.d"]; "mixed")]
+    #[test_case(&["a", "a.b"], &["a"], &["a"]; "duplicates")]
+    #[test_case(&[], &["a"], &[]; "empty")]
+    fn intersection(lhs: &[&str], rhs: &[&str], want: &[&str]) {
+        assert_eq!(mask(lhs).intersection(&mask(rhs)), mask(want));
+        assert_eq!(mask(rhs).intersection(&mask(lhs)), mask(want));
+    }
+
+    #[test_case("a", true)]
+    #[test_case("a.b.c", true)]
+    #[test_case("b.c", true)]
+    #[test_case("b", false)]
+    #[test_case("ab", false)]
+    #[test_case("b.cd", false)]
+    #[test_case("", false)]
+    fn contains(path: &str, want: bool) {
+        let fm = mask(&["a", "b.c"]);
+        assert_eq!(fm.contains(path), want, "{path}");
+    }
 }
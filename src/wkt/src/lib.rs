@@ -20,6 +20,10 @@ mod empty;
 pub use crate::empty::*;
 mod field_mask;
 pub use crate::field_mask::*;
+mod message;
+pub use crate::message::*;
+mod r#struct;
+pub use crate::r#struct::*;
 mod timestamp;
 pub use crate::timestamp::*;
 mod wrappers;
@@ -0,0 +1,280 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::AnyError as Error;
+
+type Map = serde_json::Map<String, serde_json::Value>;
+
+/// A trait implemented by all Protobuf messages.
+///
+/// Every well-known type, and every message in the generated libraries,
+/// implements this trait. It provides the fully qualified name of the
+/// message type, which is needed to pack and unpack the message in an
+/// [Any][crate::Any].
+pub trait Message: serde::ser::Serialize + serde::de::DeserializeOwned {
+    /// The fully qualified name of the message type, for example
+    /// `google.protobuf.Duration`.
+    fn typename() -> &'static str;
+
+    /// Converts the message into the fields of an [Any][crate::Any], other
+    /// than `@type`.
+    ///
+    /// Most messages are represented by their own fields. Well-known types
+    /// with a custom JSON representation override this function.
+    #[doc(hidden)]
+    fn to_map(&self) -> Result<Map, Error> {
+        let value = serde_json::to_value(self).map_err(|e| Error::SerializationError(e.into()))?;
+        match value {
+            serde_json::Value::Object(map) => Ok(map),
+            _ => Err(Error::SerializationError(Box::from(format!(
+                "expected the JSON representation of {} to be an object",
+                Self::typename()
+            )))),
+        }
+    }
+
+    /// Converts the fields of an [Any][crate::Any] into the message.
+    #[doc(hidden)]
+    fn from_map(map: &Map) -> Result<Self, Error> {
+        let mut map = map.clone();
+        map.remove("@type");
+        serde_json::from_value(serde_json::Value::Object(map))
+            .map_err(|e| Error::DeserializationError(e.into()))
+    }
+}
+
+/// Implements [Message::to_map] for well-known types with a custom JSON
+/// representation, these are stored in a `value` field.
+pub(crate) fn to_value_map<T>(message: &T) -> Result<Map, Error>
+where
+    T: serde::ser::Serialize,
+{
+    let value = serde_json::to_value(message).map_err(|e| Error::SerializationError(e.into()))?;
+    Ok(Map::from_iter([("value".to_string(), value)]))
+}
+
+/// Implements [Message::from_map] for well-known types with a custom JSON
+/// representation, these are stored in a `value` field.
+pub(crate) fn from_value_map<T>(map: &Map) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let value = map
+        .get("value")
+        .ok_or_else(|| Error::DeserializationError(Box::from("missing `value` field in Any")))?;
+    serde_json::from_value(value.clone()).map_err(|e| Error::DeserializationError(e.into()))
+}
+
+macro_rules! well_known_message {
+    ($t:ty, $name:literal) => {
+        impl Message for $t {
+            fn typename() -> &'static str {
+                $name
+            }
+        }
+    };
+    ($t:ty, $name:literal, value) => {
+        impl Message for $t {
+            fn typename() -> &'static str {
+                $name
+            }
+            fn to_map(&self) -> Result<Map, Error> {
+                to_value_map(self)
+            }
+            fn from_map(map: &Map) -> Result<Self, Error> {
+                from_value_map(map)
+            }
+        }
+    };
+}
+
+well_known_message!(crate::Empty, "google.protobuf.Empty");
+well_known_message!(crate::Any, "google.protobuf.Any", value);
+well_known_message!(crate::Duration, "google.protobuf.Duration", value);
+well_known_message!(crate::FieldMask, "google.protobuf.FieldMask", value);
+well_known_message!(crate::Timestamp, "google.protobuf.Timestamp", value);
+well_known_message!(crate::Struct, "google.protobuf.Struct", value);
+well_known_message!(crate::Value, "google.protobuf.Value", value);
+well_known_message!(crate::ListValue, "google.protobuf.ListValue", value);
+well_known_message!(crate::DoubleValue, "google.protobuf.DoubleValue", value);
+well_known_message!(crate::FloatValue, "google.protobuf.FloatValue", value);
+well_known_message!(crate::Int32Value, "google.protobuf.Int32Value", value);
+well_known_message!(crate::UInt32Value, "google.protobuf.UInt32Value", value);
+well_known_message!(crate::BoolValue, "google.protobuf.BoolValue", value);
+well_known_message!(crate::StringValue, "google.protobuf.StringValue", value);
+
+/// The JSON representation for `Int64Value` is a JSON string.
+impl Message for crate::Int64Value {
+    fn typename() -> &'static str {
+        "google.protobuf.Int64Value"
+    }
+    fn to_map(&self) -> Result<Map, Error> {
+        to_value_map(&self.to_string())
+    }
+    fn from_map(map: &Map) -> Result<Self, Error> {
+        from_string_map(map)
+    }
+}
+
+/// The JSON representation for `UInt64Value` is a JSON string.
+impl Message for crate::UInt64Value {
+    fn typename() -> &'static str {
+        "google.protobuf.UInt64Value"
+    }
+    fn to_map(&self) -> Result<Map, Error> {
+        to_value_map(&self.to_string())
+    }
+    fn from_map(map: &Map) -> Result<Self, Error> {
+        from_string_map(map)
+    }
+}
+
+/// The JSON representation for `BytesValue` is a base64-encoded JSON string.
+impl Message for crate::BytesValue {
+    fn typename() -> &'static str {
+        "google.protobuf.BytesValue"
+    }
+    fn to_map(&self) -> Result<Map, Error> {
+        use serde_with::{base64::Base64, ser::SerializeAsWrap};
+        to_value_map(&SerializeAsWrap::<Self, Base64>::new(self))
+    }
+    fn from_map(map: &Map) -> Result<Self, Error> {
+        use serde_with::{base64::Base64, de::DeserializeAsWrap};
+        from_value_map::<DeserializeAsWrap<Self, Base64>>(map).map(DeserializeAsWrap::into_inner)
+    }
+}
+
+/// 64-bit integers are encoded as JSON strings, but parsers also accept
+/// JSON numbers.
+fn from_string_map<T>(map: &Map) -> Result<T, Error>
+where
+    T: std::str::FromStr + serde::de::DeserializeOwned,
+    T::Err: std::error::Error + 'static,
+{
+    match map.get("value") {
+        Some(serde_json::Value::String(s)) => s
+            .parse::<T>()
+            .map_err(|e| Error::DeserializationError(e.into())),
+        _ => from_value_map(map),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::*;
+    use serde_json::json;
+    use test_case::test_case;
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    fn typename<T: Message>(_: &T) -> &'static str {
+        T::typename()
+    }
+
+    #[test_case(typename(&Empty::default()), "google.protobuf.Empty")]
+    #[test_case(typename(&Any::default()), "google.protobuf.Any")]
+    #[test_case(typename(&Duration::default()), "google.protobuf.Duration")]
+    #[test_case(typename(&FieldMask::default()), "google.protobuf.FieldMask")]
+    #[test_case(typename(&Timestamp::default()), "google.protobuf.Timestamp")]
+    #[test_case(typename(&Struct::default()), "google.protobuf.Struct")]
+    #[test_case(typename(&Value::default()), "google.protobuf.Value")]
+    #[test_case(typename(&ListValue::default()), "google.protobuf.ListValue")]
+    #[test_case(typename(&0_f64), "google.protobuf.DoubleValue")]
+    #[test_case(typename(&0_f32), "google.protobuf.FloatValue")]
+    #[test_case(typename(&0_i64), "google.protobuf.Int64Value")]
+    #[test_case(typename(&0_u64), "google.protobuf.UInt64Value")]
+    #[test_case(typename(&0_i32), "google.protobuf.Int32Value")]
+    #[test_case(typename(&0_u32), "google.protobuf.UInt32Value")]
+    #[test_case(typename(&false), "google.protobuf.BoolValue")]
+    #[test_case(typename(&String::new()), "google.protobuf.StringValue")]
+    #[test_case(typename(&bytes::Bytes::new()), "google.protobuf.BytesValue")]
+    fn typenames(got: &str, want: &str) {
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn empty() -> Result {
+        let map = Empty::default().to_map()?;
+        assert!(map.is_empty(), "{map:?}");
+        let got = Empty::from_map(&Map::from_iter([("@type".to_string(), json!("ignored"))]))?;
+        assert_eq!(got, Empty::default());
+        Ok(())
+    }
+
+    #[test]
+    fn value_fields() -> Result {
+        let map = Duration::clamp(60, 0).to_map()?;
+        assert_eq!(
+            serde_json::Value::Object(map.clone()),
+            json!({"value": "60s"})
+        );
+        assert_eq!(Duration::from_map(&map)?, Duration::clamp(60, 0));
+
+        let got = Duration::from_map(&Map::new());
+        assert!(
+            matches!(got, Err(Error::DeserializationError(_))),
+            "{got:?}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn int64() -> Result {
+        let map = 42_i64.to_map()?;
+        assert_eq!(
+            serde_json::Value::Object(map.clone()),
+            json!({"value": "42"})
+        );
+        assert_eq!(i64::from_map(&map)?, 42);
+        let map = Map::from_iter([("value".to_string(), json!(-7))]);
+        assert_eq!(i64::from_map(&map)?, -7);
+
+        let map = u64::MAX.to_map()?;
+        assert_eq!(
+            serde_json::Value::Object(map.clone()),
+            json!({"value": u64::MAX.to_string()})
+        );
+        assert_eq!(u64::from_map(&map)?, u64::MAX);
+
+        let map = Map::from_iter([("value".to_string(), json!("abc"))]);
+        let got = i64::from_map(&map);
+        assert!(got.is_err(), "{got:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn bytes() -> Result {
+        let input = bytes::Bytes::from_static(b"the quick brown fox");
+        let map = input.to_map()?;
+        assert_eq!(
+            serde_json::Value::Object(map.clone()),
+            json!({"value": "dGhlIHF1aWNrIGJyb3duIGZveA=="})
+        );
+        assert_eq!(bytes::Bytes::from_map(&map)?, input);
+        Ok(())
+    }
+
+    #[test]
+    fn not_an_object() {
+        #[derive(Debug, serde::Deserialize, serde::Serialize)]
+        struct NotAnObject(i32);
+        impl Message for NotAnObject {
+            fn typename() -> &'static str {
+                "test.NotAnObject"
+            }
+        }
+        let got = NotAnObject(0).to_map();
+        assert!(matches!(got, Err(Error::SerializationError(_))), "{got:?}");
+    }
+}
@@ -0,0 +1,849 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+/// `Struct` represents a structured data value, consisting of fields
+/// which map to dynamically typed values. In some languages, `Struct`
+/// might be supported by a native representation. For example, in
+/// scripting languages like JS a struct is represented as an
+/// object. The details of that representation are described together
+/// with the proto support for the language.
+///
+/// The fields are kept in a [BTreeMap], so iteration and serialization
+/// produce the keys in a stable (sorted) order.
+///
+/// The JSON representation for `Struct` is JSON object.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct Struct {
+    /// Unordered map of dynamically typed values.
+    pub fields: BTreeMap<String, Value>,
+}
+
+/// `Value` represents a dynamically typed value which can be either
+/// null, a number, a string, a boolean, a recursive struct value, or a
+/// list of values. A producer of value is expected to set one of these
+/// variants. Absence of any variant indicates an error.
+///
+/// The JSON representation for `Value` is JSON value.
+///
+/// Note that JSON numbers are always represented as `f64`. Non-finite
+/// numbers (`NaN` and the infinities) have no JSON representation, and
+/// fail to serialize.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub enum Value {
+    /// Represents a null value.
+    #[default]
+    Null,
+    /// Represents a double value.
+    Number(f64),
+    /// Represents a string value.
+    String(String),
+    /// Represents a boolean value.
+    Bool(bool),
+    /// Represents a structured value.
+    Struct(Struct),
+    /// Represents a repeated `Value`.
+    List(ListValue),
+}
+
+/// `ListValue` is a wrapper around a repeated field of values.
+///
+/// The JSON representation for `ListValue` is JSON array.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct ListValue {
+    /// Repeated field of dynamically typed values.
+    pub values: Vec<Value>,
+}
+
+/// `NullValue` is a singleton enumeration to represent the null value for the
+/// `Value` type union.
+///
+/// The JSON representation for `NullValue` is JSON `null`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NullValue;
+
+/// Errors converting [Value], [Struct] or [ListValue] to [serde_json::Value].
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum ValueError {
+    #[error("non-finite numbers cannot be represented in JSON, got {0}")]
+    NonFiniteNumber(f64),
+}
+
+impl Struct {
+    /// Creates an empty `Struct`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value of the field named `key`, if present.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.get(key)
+    }
+
+    /// Returns a mutable reference to the field named `key`, if present.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+        self.fields.get_mut(key)
+    }
+
+    /// Sets the field named `key`, returning the previous value, if any.
+    pub fn insert<K: Into<String>, V: Into<Value>>(&mut self, key: K, value: V) -> Option<Value> {
+        self.fields.insert(key.into(), value.into())
+    }
+
+    /// Removes the field named `key`, returning its value, if any.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.fields.remove(key)
+    }
+
+    /// Returns true if the struct contains a field named `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.fields.contains_key(key)
+    }
+
+    /// The number of fields in the struct.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Returns true if the struct has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Iterates over the fields, in key order.
+    pub fn iter(&self) -> std::collections::btree_map::Iter<'_, String, Value> {
+        self.fields.iter()
+    }
+
+    /// Sets the field named `key` and returns the modified struct.
+    pub fn set<K: Into<String>, V: Into<Value>>(mut self, key: K, value: V) -> Self {
+        self.insert(key, value);
+        self
+    }
+}
+
+impl ListValue {
+    /// Creates an empty `ListValue`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the element at `index`, if present.
+    pub fn get(&self, index: usize) -> Option<&Value> {
+        self.values.get(index)
+    }
+
+    /// Appends a value to the list.
+    pub fn push<V: Into<Value>>(&mut self, value: V) {
+        self.values.push(value.into())
+    }
+
+    /// The number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Iterates over the elements of the list.
+    pub fn iter(&self) -> std::slice::Iter<'_, Value> {
+        self.values.iter()
+    }
+}
+
+impl Value {
+    /// Returns true if the value is [Value::Null].
+    pub fn is_null(&self) -> bool {
+        matches!(self, Value::Null)
+    }
+
+    /// Returns the number, if the value is a [Value::Number].
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Returns the string, if the value is a [Value::String].
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean, if the value is a [Value::Bool].
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the struct, if the value is a [Value::Struct].
+    pub fn as_struct(&self) -> Option<&Struct> {
+        match self {
+            Value::Struct(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the struct, if the value is a [Value::Struct].
+    pub fn as_struct_mut(&mut self) -> Option<&mut Struct> {
+        match self {
+            Value::Struct(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the list, if the value is a [Value::List].
+    pub fn as_list(&self) -> Option<&ListValue> {
+        match self {
+            Value::List(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// Returns the list, if the value is a [Value::List].
+    pub fn as_list_mut(&mut self) -> Option<&mut ListValue> {
+        match self {
+            Value::List(l) => Some(l),
+            _ => None,
+        }
+    }
+
+    /// Returns the field named `key` if the value is a [Value::Struct] and
+    /// the field is present.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_struct().and_then(|s| s.get(key))
+    }
+}
+
+impl From<NullValue> for Value {
+    fn from(_: NullValue) -> Self {
+        Value::Null
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Value::Number(value.into())
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Number(value.into())
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::Number(value.into())
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<Struct> for Value {
+    fn from(value: Struct) -> Self {
+        Value::Struct(value)
+    }
+}
+
+impl From<ListValue> for Value {
+    fn from(value: ListValue) -> Self {
+        Value::List(value)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(value: Vec<T>) -> Self {
+        Value::List(value.into())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or_default()
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for ListValue {
+    fn from(value: Vec<T>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl<V: Into<Value>> FromIterator<V> for ListValue {
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl IntoIterator for ListValue {
+    type Item = Value;
+    type IntoIter = std::vec::IntoIter<Value>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>> FromIterator<(K, V)> for Struct {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            fields: iter
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        }
+    }
+}
+
+impl IntoIterator for Struct {
+    type Item = (String, Value);
+    type IntoIter = std::collections::btree_map::IntoIter<String, Value>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.fields.into_iter()
+    }
+}
+
+impl From<serde_json::Value> for Value {
+    fn from(value: serde_json::Value) -> Self {
+        use serde_json::Value as Json;
+        match value {
+            Json::Null => Value::Null,
+            // All JSON numbers are representable as (possibly rounded) f64.
+            Json::Number(n) => Value::Number(n.as_f64().unwrap_or_default()),
+            Json::String(s) => Value::String(s),
+            Json::Bool(b) => Value::Bool(b),
+            Json::Object(o) => Value::Struct(o.into()),
+            Json::Array(a) => Value::List(a.into()),
+        }
+    }
+}
+
+impl From<serde_json::Map<String, serde_json::Value>> for Struct {
+    fn from(value: serde_json::Map<String, serde_json::Value>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl TryFrom<Value> for serde_json::Value {
+    type Error = ValueError;
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        use serde_json::Value as Json;
+        let json = match value {
+            Value::Null => Json::Null,
+            Value::Number(n) => {
+                Json::Number(serde_json::Number::from_f64(n).ok_or(ValueError::NonFiniteNumber(n))?)
+            }
+            Value::String(s) => Json::String(s),
+            Value::Bool(b) => Json::Bool(b),
+            Value::Struct(s) => Json::Object(s.try_into()?),
+            Value::List(l) => Json::Array(l.try_into()?),
+        };
+        Ok(json)
+    }
+}
+
+impl TryFrom<Struct> for serde_json::Map<String, serde_json::Value> {
+    type Error = ValueError;
+    fn try_from(value: Struct) -> Result<Self, Self::Error> {
+        value
+            .fields
+            .into_iter()
+            .map(|(k, v)| Ok((k, v.try_into()?)))
+            .collect()
+    }
+}
+
+impl TryFrom<ListValue> for Vec<serde_json::Value> {
+    type Error = ValueError;
+    fn try_from(value: ListValue) -> Result<Self, Self::Error> {
+        value.values.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+/// Implement [`serde`](::serde) serialization for [Value].
+impl serde::ser::Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        use serde::ser::Error as _;
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::Number(n) if n.is_finite() => serializer.serialize_f64(*n),
+            Value::Number(n) => Err(S::Error::custom(ValueError::NonFiniteNumber(*n))),
+            Value::String(s) => serializer.serialize_str(s),
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Struct(s) => s.serialize(serializer),
+            Value::List(l) => l.serialize(serializer),
+        }
+    }
+}
+
+/// Implement [`serde`](::serde) deserialization for [Value].
+impl<'de> serde::de::Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("any JSON value")
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::de::Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_bool<E>(self, value: bool) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Bool(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Number(value as f64))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Number(value as f64))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> std::result::Result<Self::Value, E> {
+        Ok(Value::Number(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E> {
+        Ok(Value::String(value.to_string()))
+    }
+
+    fn visit_string<E>(self, value: String) -> std::result::Result<Self::Value, E> {
+        Ok(Value::String(value))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        ListValueVisitor.visit_seq(seq).map(Value::List)
+    }
+
+    fn visit_map<A>(self, map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        StructVisitor.visit_map(map).map(Value::Struct)
+    }
+}
+
+/// Implement [`serde`](::serde) serialization for [Struct].
+impl serde::ser::Serialize for Struct {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_map(&self.fields)
+    }
+}
+
+/// Implement [`serde`](::serde) deserialization for [Struct].
+impl<'de> serde::de::Deserialize<'de> for Struct {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(StructVisitor)
+    }
+}
+
+struct StructVisitor;
+
+impl<'de> serde::de::Visitor<'de> for StructVisitor {
+    type Value = Struct;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a JSON object")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        let mut fields = BTreeMap::new();
+        while let Some((k, v)) = map.next_entry::<String, Value>()? {
+            fields.insert(k, v);
+        }
+        Ok(Struct { fields })
+    }
+}
+
+/// Implement [`serde`](::serde) serialization for [ListValue].
+impl serde::ser::Serialize for ListValue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.collect_seq(&self.values)
+    }
+}
+
+/// Implement [`serde`](::serde) deserialization for [ListValue].
+impl<'de> serde::de::Deserialize<'de> for ListValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ListValueVisitor)
+    }
+}
+
+struct ListValueVisitor;
+
+impl<'de> serde::de::Visitor<'de> for ListValueVisitor {
+    type Value = ListValue;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a JSON array")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut values = Vec::new();
+        while let Some(v) = seq.next_element::<Value>()? {
+            values.push(v);
+        }
+        Ok(ListValue { values })
+    }
+}
+
+/// Implement [`serde`](::serde) serialization for [NullValue].
+impl serde::ser::Serialize for NullValue {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+/// Implement [`serde`](::serde) deserialization for [NullValue].
+impl<'de> serde::de::Deserialize<'de> for NullValue {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(NullValueVisitor)
+    }
+}
+
+struct NullValueVisitor;
+
+impl serde::de::Visitor<'_> for NullValueVisitor {
+    type Value = NullValue;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a JSON null or the NULL_VALUE enum name")
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(NullValue)
+    }
+
+    fn visit_none<E>(self) -> std::result::Result<Self::Value, E> {
+        Ok(NullValue)
+    }
+
+    // ProtoJSON parsers accept enum values by name or number.
+    fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match value {
+            "NULL_VALUE" => Ok(NullValue),
+            _ => Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+        }
+    }
+
+    fn visit_u64<E>(self, value: u64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match value {
+            0 => Ok(NullValue),
+            _ => Err(E::invalid_value(
+                serde::de::Unexpected::Unsigned(value),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_i64<E>(self, value: i64) -> std::result::Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match value {
+            0 => Ok(NullValue),
+            _ => Err(E::invalid_value(
+                serde::de::Unexpected::Signed(value),
+                &self,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use test_case::test_case;
+    type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+    #[test_case(json!(null), Value::Null)]
+    #[test_case(json!(1.5), Value::Number(1.5))]
+    #[test_case(json!(42), Value::Number(42.0))]
+    #[test_case(json!(-7), Value::Number(-7.0))]
+    #[test_case(json!("abc"), Value::String("abc".into()))]
+    #[test_case(json!(true), Value::Bool(true))]
+    #[test_case(json!([]), Value::List(ListValue::new()))]
+    #[test_case(json!({}), Value::Struct(Struct::new()))]
+    fn scalar_roundtrip(input: serde_json::Value, want: Value) -> Result {
+        let got = serde_json::from_value::<Value>(input.clone())?;
+        assert_eq!(got, want);
+        let json = serde_json::to_value(&got)?;
+        assert_eq!(json.as_f64(), input.as_f64());
+        assert_eq!(serde_json::from_value::<Value>(json)?, want);
+        Ok(())
+    }
+
+    #[test]
+    fn nested_roundtrip() -> Result {
+        let input = json!({
+            "name": "test",
+            "count": 3,
+            "enabled": false,
+            "missing": null,
+            "tags": ["a", 1, null, {"k": "v"}],
+            "nested": {"inner": {"deep": [true]}},
+        });
+        let value = serde_json::from_value::<Value>(input.clone())?;
+        let object = value.as_struct().unwrap();
+        assert_eq!(object.len(), 6);
+        assert_eq!(value.get("name").and_then(Value::as_str), Some("test"));
+        assert_eq!(value.get("count").and_then(Value::as_f64), Some(3.0));
+        assert_eq!(value.get("enabled").and_then(Value::as_bool), Some(false));
+        assert!(value.get("missing").map(Value::is_null).unwrap_or(false));
+        let tags = value.get("tags").and_then(Value::as_list).unwrap();
+        assert_eq!(tags.len(), 4);
+        assert_eq!(tags.get(0), Some(&Value::from("a")));
+        assert_eq!(
+            value
+                .get("nested")
+                .and_then(|v| v.get("inner"))
+                .and_then(|v| v.get("deep")),
+            Some(&Value::from(vec![true]))
+        );
+
+        let json = serde_json::to_value(&value)?;
+        let want = json!({
+            "name": "test",
+            "count": 3.0,
+            "enabled": false,
+            "missing": null,
+            "tags": ["a", 1.0, null, {"k": "v"}],
+            "nested": {"inner": {"deep": [true]}},
+        });
+        assert_eq!(json, want);
+        Ok(())
+    }
+
+    #[test]
+    fn struct_serialize() -> Result {
+        let s = Struct::new()
+            .set("b", 2)
+            .set("a", "x")
+            .set("c", Option::<bool>::None);
+        assert_eq!(serde_json::to_string(&s)?, r#"{"a":"x","b":2.0,"c":null}"#);
+        let roundtrip = serde_json::from_str::<Struct>(&serde_json::to_string(&s)?)?;
+        assert_eq!(roundtrip, s);
+        Ok(())
+    }
+
+    #[test]
+    fn struct_accessors() {
+        let mut s = Struct::new();
+        assert!(s.is_empty());
+        assert_eq!(s.insert("k", 1), None);
+        assert_eq!(s.insert("k", 2), Some(Value::Number(1.0)));
+        assert!(s.contains_key("k"));
+        if let Some(v) = s.get_mut("k") {
+            *v = Value::from("changed");
+        }
+        assert_eq!(s.get("k").and_then(Value::as_str), Some("changed"));
+        assert_eq!(s.iter().count(), 1);
+        assert_eq!(s.remove("k"), Some(Value::from("changed")));
+        assert_eq!(s.get("k"), None);
+    }
+
+    #[test]
+    fn list_accessors() {
+        let mut l = ListValue::from(vec![1, 2]);
+        l.push("three");
+        assert_eq!(l.len(), 3);
+        assert_eq!(l.get(2).and_then(Value::as_str), Some("three"));
+        let numbers: Vec<f64> = l.iter().filter_map(Value::as_f64).collect();
+        assert_eq!(numbers, vec![1.0, 2.0]);
+        let values: Vec<Value> = l.into_iter().collect();
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn value_accessors_mismatch() {
+        let v = Value::from(1.5);
+        assert!(!v.is_null());
+        assert_eq!(v.as_str(), None);
+        assert_eq!(v.as_bool(), None);
+        assert_eq!(v.as_struct(), None);
+        assert_eq!(v.as_list(), None);
+        assert_eq!(v.get("any"), None);
+        assert_eq!(Value::default(), Value::Null);
+        assert_eq!(Value::from(NullValue), Value::Null);
+    }
+
+    #[test]
+    fn from_json() {
+        let json = json!({"a": [1, "b", {"c": null}]});
+        let got = Value::from(json);
+        let want = Value::from(Struct::new().set(
+            "a",
+            ListValue::from(vec![
+                Value::from(1),
+                Value::from("b"),
+                Value::from(Struct::new().set("c", NullValue)),
+            ]),
+        ));
+        assert_eq!(got, want);
+    }
+
+    #[test]
+    fn to_json() -> Result {
+        let value = Value::from(Struct::new().set("a", vec![1.5, 2.0]).set("b", true));
+        let got = serde_json::Value::try_from(value)?;
+        assert_eq!(got, json!({"a": [1.5, 2.0], "b": true}));
+
+        let s = Struct::new().set("x", "y");
+        let got = serde_json::Map::try_from(s)?;
+        assert_eq!(serde_json::Value::Object(got), json!({"x": "y"}));
+        Ok(())
+    }
+
+    #[test_case(f64::NAN)]
+    #[test_case(f64::INFINITY)]
+    #[test_case(f64::NEG_INFINITY)]
+    fn non_finite(n: f64) {
+        let value = Value::from(Struct::new().set("n", vec![n]));
+        let got = serde_json::Value::try_from(value.clone());
+        assert!(
+            matches!(got, Err(ValueError::NonFiniteNumber(_))),
+            "{got:?}"
+        );
+        let got = serde_json::to_value(&value);
+        assert!(got.is_err(), "{got:?}");
+    }
+
+    #[test_case(json!(null))]
+    #[test_case(json!("NULL_VALUE"))]
+    #[test_case(json!(0))]
+    fn null_value_deserialize(input: serde_json::Value) -> Result {
+        let got = serde_json::from_value::<NullValue>(input)?;
+        assert_eq!(got, NullValue);
+        Ok(())
+    }
+
+    #[test_case(json!("OTHER"))]
+    #[test_case(json!(1))]
+    #[test_case(json!({}))]
+    fn null_value_deserialize_error(input: serde_json::Value) {
+        let got = serde_json::from_value::<NullValue>(input);
+        assert!(got.is_err(), "{got:?}");
+    }
+
+    #[test]
+    fn null_value_serialize() -> Result {
+        assert_eq!(serde_json::to_value(NullValue)?, json!(null));
+        Ok(())
+    }
+
+    #[test]
+    fn struct_deserialize_error() {
+        let got = serde_json::from_value::<Struct>(json!([1, 2]));
+        assert!(got.is_err(), "{got:?}");
+        let got = serde_json::from_value::<ListValue>(json!({"a": 1}));
+        assert!(got.is_err(), "{got:?}");
+    }
+}
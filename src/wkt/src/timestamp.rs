@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::Duration;
+
 ///
 /// Well-known point in time representation for Google APIs.
 ///
@@ -43,7 +45,7 @@
 /// For example, "2017-01-15T01:30:15.01Z" encodes 15.01 seconds past
 /// 01:30 UTC on January 15, 2017.
 ///
-#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[non_exhaustive]
 pub struct Timestamp {
     /// Represents seconds of UTC time since Unix epoch
@@ -133,6 +135,163 @@ impl Timestamp {
     pub fn nanos(&self) -> i32 {
         self.nanos
     }
+
+    /// Returns the current time.
+    ///
+    /// The system clock is assumed to be within the valid range for a
+    /// [Timestamp], the result is clamped otherwise.
+    pub fn now() -> Self {
+        let nanos = match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => d.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        };
+        let saturated = if nanos < 0 {
+            Self {
+                seconds: Self::MIN_SECONDS,
+                nanos: Self::MIN_NANOS,
+            }
+        } else {
+            Self {
+                seconds: Self::MAX_SECONDS,
+                nanos: Self::MAX_NANOS,
+            }
+        };
+        Self::from_nanos(nanos).unwrap_or(saturated)
+    }
+
+    /// Computes `self + rhs`, returning `None` if the result is out of range.
+    pub fn checked_add(&self, rhs: &Duration) -> Option<Timestamp> {
+        Self::from_nanos(self.as_nanos() + rhs.as_nanos()).ok()
+    }
+
+    /// Computes `self - rhs`, returning `None` if the result is out of range.
+    pub fn checked_sub(&self, rhs: &Duration) -> Option<Timestamp> {
+        Self::from_nanos(self.as_nanos() - rhs.as_nanos()).ok()
+    }
+
+    /// Computes the [Duration] between `earlier` and `self`.
+    ///
+    /// Returns `None` if either timestamp is out of range. The difference
+    /// between two valid timestamps is always a valid [Duration].
+    pub fn checked_duration_since(&self, earlier: &Timestamp) -> Option<Duration> {
+        Self::new(self.seconds, self.nanos).ok()?;
+        Self::new(earlier.seconds, earlier.nanos).ok()?;
+        Duration::from_nanos(self.as_nanos() - earlier.as_nanos()).ok()
+    }
+
+    /// The number of nanoseconds since the Unix epoch.
+    fn as_nanos(&self) -> i128 {
+        self.seconds as i128 * NS + self.nanos as i128
+    }
+
+    /// Creates a [Timestamp] from the number of nanoseconds since the Unix
+    /// epoch.
+    fn from_nanos(nanos: i128) -> std::result::Result<Self, Error> {
+        let seconds = nanos.div_euclid(NS);
+        if !(Self::MIN_SECONDS as i128..=Self::MAX_SECONDS as i128).contains(&seconds) {
+            return Err(Error::OutOfRange());
+        }
+        Self::new(seconds as i64, nanos.rem_euclid(NS) as i32)
+    }
+}
+
+/// Adds a [Duration] to a [Timestamp].
+///
+/// # Panics
+///
+/// Panics if the result is out of range, use [Timestamp::checked_add] to
+/// handle this case.
+impl std::ops::Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn add(self, rhs: Duration) -> Self::Output {
+        self.checked_add(&rhs)
+            .expect("overflow when adding duration to timestamp")
+    }
+}
+
+/// Subtracts a [Duration] from a [Timestamp].
+///
+/// # Panics
+///
+/// Panics if the result is out of range, use [Timestamp::checked_sub] to
+/// handle this case.
+impl std::ops::Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    fn sub(self, rhs: Duration) -> Self::Output {
+        self.checked_sub(&rhs)
+            .expect("overflow when subtracting duration from timestamp")
+    }
+}
+
+/// Computes the [Duration] between two [Timestamp] values.
+///
+/// # Panics
+///
+/// Panics if either timestamp is out of range, use
+/// [Timestamp::checked_duration_since] to handle this case.
+impl std::ops::Sub for Timestamp {
+    type Output = Duration;
+
+    fn sub(self, rhs: Timestamp) -> Self::Output {
+        self.checked_duration_since(&rhs)
+            .expect("timestamp out of range")
+    }
+}
+
+impl std::ops::AddAssign<Duration> for Timestamp {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = std::mem::take(self) + rhs;
+    }
+}
+
+impl std::ops::SubAssign<Duration> for Timestamp {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = std::mem::take(self) - rhs;
+    }
+}
+
+/// Convert from [SystemTime][std::time::SystemTime] to [Timestamp].
+///
+/// This conversion may fail if the [SystemTime][std::time::SystemTime] value is
+/// out of range.
+impl TryFrom<std::time::SystemTime> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: std::time::SystemTime) -> std::result::Result<Self, Self::Error> {
+        let nanos = match value.duration_since(std::time::UNIX_EPOCH) {
+            Ok(d) => i128::try_from(d.as_nanos()).map_err(|_| Error::OutOfRange())?,
+            Err(e) => -i128::try_from(e.duration().as_nanos()).map_err(|_| Error::OutOfRange())?,
+        };
+        Self::from_nanos(nanos)
+    }
+}
+
+/// Convert from [Timestamp] to [SystemTime][std::time::SystemTime].
+///
+/// This conversion may fail if the [Timestamp] value is out of range, or if
+/// it cannot be represented by the platform's
+/// [SystemTime][std::time::SystemTime].
+impl TryFrom<Timestamp> for std::time::SystemTime {
+    type Error = TimestampError;
+
+    fn try_from(value: Timestamp) -> std::result::Result<Self, Self::Error> {
+        let value = Timestamp::new(value.seconds, value.nanos)?;
+        let epoch = std::time::UNIX_EPOCH;
+        let time = if value.seconds >= 0 {
+            epoch.checked_add(std::time::Duration::new(
+                value.seconds as u64,
+                value.nanos as u32,
+            ))
+        } else {
+            // `nanos` counts forward in time, even for negative `seconds`.
+            epoch
+                .checked_sub(std::time::Duration::from_secs(value.seconds.unsigned_abs()))
+                .and_then(|t| t.checked_add(std::time::Duration::from_nanos(value.nanos as u64)))
+        };
+        time.ok_or(Error::OutOfRange())
+    }
 }
 
 use time::format_description::well_known::Rfc3339;
@@ -215,6 +374,35 @@ impl TryFrom<Timestamp> for time::OffsetDateTime {
     }
 }
 
+/// Convert from [chrono::DateTime<Utc>][chrono::DateTime] to [Timestamp].
+///
+/// This conversion may fail if the [chrono::DateTime] value is out of range,
+/// or if it represents a leap second.
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::Utc>> for Timestamp {
+    type Error = TimestampError;
+
+    fn try_from(value: chrono::DateTime<chrono::Utc>) -> std::result::Result<Self, Self::Error> {
+        let nanos =
+            i32::try_from(value.timestamp_subsec_nanos()).map_err(|_| Error::OutOfRange())?;
+        Self::new(value.timestamp(), nanos)
+    }
+}
+
+/// Convert from [Timestamp] to [chrono::DateTime<Utc>][chrono::DateTime].
+///
+/// This conversion may fail if the [Timestamp] value is out of range.
+#[cfg(feature = "chrono")]
+impl TryFrom<Timestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = TimestampError;
+
+    fn try_from(value: Timestamp) -> std::result::Result<Self, Self::Error> {
+        let value = Timestamp::new(value.seconds, value.nanos)?;
+        chrono::DateTime::from_timestamp(value.seconds, value.nanos as u32)
+            .ok_or(Error::OutOfRange())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -299,4 +487,176 @@ mod test {
         assert!(got.is_err());
         Ok(())
     }
+
+    #[test_case(Timestamp::clamp(0, 0), Duration::clamp(1, 500_000_000), Timestamp::clamp(1, 500_000_000) ; "positive")]
+    #[test_case(Timestamp::clamp(0, 0), Duration::clamp(-1, -500_000_000), Timestamp::clamp(-2, 500_000_000) ; "negative")]
+    #[test_case(Timestamp::clamp(-1, 900_000_000), Duration::clamp(0, 200_000_000), Timestamp::clamp(0, 100_000_000) ; "carry")]
+    #[test_case(Timestamp::clamp(Timestamp::MAX_SECONDS, 0), Duration::clamp(0, 999_999_999), Timestamp::new(Timestamp::MAX_SECONDS, 999_999_999).unwrap() ; "max")]
+    fn add(ts: Timestamp, d: Duration, want: Timestamp) {
+        assert_eq!(ts.clone() + d.clone(), want);
+        assert_eq!(ts.checked_add(&d), Some(want.clone()));
+        assert_eq!(want.clone() - d.clone(), ts);
+        assert_eq!(want.checked_sub(&d), Some(ts.clone()));
+        assert_eq!(want.clone() - ts.clone(), d);
+        assert_eq!(want.checked_duration_since(&ts), Some(d.clone()));
+        let mut got = ts.clone();
+        got += d.clone();
+        assert_eq!(got, want);
+        got -= d;
+        assert_eq!(got, ts);
+    }
+
+    #[test]
+    fn checked_overflow() {
+        let min = Timestamp::clamp(Timestamp::MIN_SECONDS, 0);
+        let max = Timestamp::new(Timestamp::MAX_SECONDS, Timestamp::MAX_NANOS).unwrap();
+        let one = Duration::clamp(0, 1);
+        assert_eq!(min.checked_sub(&one), None);
+        assert_eq!(max.checked_add(&one), None);
+        // The full range of timestamps fits in a duration.
+        let d = max.clone() - min.clone();
+        assert_eq!(min.clone() + d.clone(), max);
+        assert_eq!(max.clone() - d, min);
+
+        let invalid = Timestamp {
+            seconds: 0,
+            nanos: -1,
+        };
+        assert_eq!(invalid.checked_duration_since(&min), None);
+        assert_eq!(max.checked_duration_since(&invalid), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn add_overflow() {
+        let _ = Timestamp::clamp(Timestamp::MAX_SECONDS, 0) + Duration::clamp(1, 0);
+    }
+
+    #[test]
+    fn now() {
+        let before = std::time::SystemTime::now();
+        let now = Timestamp::now();
+        let after = std::time::SystemTime::now();
+        let before = Timestamp::try_from(before).unwrap();
+        let after = Timestamp::try_from(after).unwrap();
+        assert!(
+            before <= now && now <= after,
+            "{before:?} {now:?} {after:?}"
+        );
+    }
+
+    #[test_case(0, 0)]
+    #[test_case(1_700_000_000, 123_456_789)]
+    #[test_case(-1, 500_000_000)]
+    #[test_case(-1_700_000_000, 1)]
+    fn system_time_roundtrip(seconds: i64, nanos: i32) -> Result {
+        let ts = Timestamp::new(seconds, nanos)?;
+        let system = std::time::SystemTime::try_from(ts.clone())?;
+        let want = if seconds >= 0 {
+            std::time::UNIX_EPOCH + std::time::Duration::new(seconds as u64, nanos as u32)
+        } else {
+            std::time::UNIX_EPOCH - std::time::Duration::from_secs(seconds.unsigned_abs())
+                + std::time::Duration::from_nanos(nanos as u64)
+        };
+        assert_eq!(system, want);
+        assert_eq!(Timestamp::try_from(system)?, ts);
+        Ok(())
+    }
+
+    #[test]
+    fn system_time_out_of_range() {
+        let invalid = Timestamp {
+            seconds: 0,
+            nanos: Timestamp::NS,
+        };
+        let got = std::time::SystemTime::try_from(invalid);
+        assert_eq!(got, Err(Error::OutOfRange()));
+
+        let far = std::time::UNIX_EPOCH
+            + std::time::Duration::from_secs(Timestamp::MAX_SECONDS as u64 + 1);
+        let got = Timestamp::try_from(far);
+        assert_eq!(got, Err(Error::OutOfRange()));
+    }
+
+    #[test]
+    fn expiration() {
+        let now = Timestamp::now();
+        let ttl = Duration::clamp(3600, 0);
+        let expire_time = now.clone() + ttl.clone();
+        assert_eq!(expire_time.clone() - now.clone(), ttl);
+        assert!(expire_time > now);
+    }
+
+    #[test_case(Timestamp::MIN_SECONDS, 0 ; "min")]
+    #[test_case(Timestamp::MAX_SECONDS, Timestamp::MAX_NANOS ; "max")]
+    #[test_case(0, 0 ; "epoch")]
+    #[test_case(-1, Timestamp::MAX_NANOS ; "just before epoch")]
+    fn chrono_roundtrip(seconds: i64, nanos: i32) -> Result {
+        let ts = Timestamp::new(seconds, nanos)?;
+        let dt = chrono::DateTime::<chrono::Utc>::try_from(ts.clone())?;
+        assert_eq!(dt.timestamp(), seconds);
+        assert_eq!(dt.timestamp_subsec_nanos(), nanos as u32);
+        assert_eq!(Timestamp::try_from(dt)?, ts);
+        Ok(())
+    }
+
+    #[test_case("0000-12-31T23:59:59.999999999Z" ; "below range")]
+    #[test_case("+10000-01-01T00:00:00Z" ; "above range")]
+    fn chrono_out_of_range(input: &str) -> Result {
+        let dt = input.parse::<chrono::DateTime<chrono::Utc>>()?;
+        let got = Timestamp::try_from(dt);
+        assert_eq!(got, Err(Error::OutOfRange()));
+        Ok(())
+    }
+
+    #[test]
+    fn chrono_leap_second() -> Result {
+        let dt = chrono::NaiveDate::from_ymd_opt(2016, 12, 31)
+            .and_then(|d| d.and_hms_nano_opt(23, 59, 59, 1_500_000_000))
+            .map(|t| t.and_utc())
+            .ok_or("cannot create leap second")?;
+        let got = Timestamp::try_from(dt);
+        assert_eq!(got, Err(Error::OutOfRange()));
+        Ok(())
+    }
+
+    #[test]
+    fn chrono_invalid_timestamp() {
+        let invalid = Timestamp {
+            seconds: Timestamp::MAX_SECONDS + 1,
+            nanos: 0,
+        };
+        let got = chrono::DateTime::<chrono::Utc>::try_from(invalid);
+        assert_eq!(got, Err(Error::OutOfRange()));
+    }
+
+    fn timestamp_seconds() -> impl proptest::strategy::Strategy<Value = i64> {
+        use proptest::prelude::*;
+        prop_oneof![
+            Timestamp::MIN_SECONDS..=Timestamp::MIN_SECONDS + 1_000,
+            Timestamp::MAX_SECONDS - 1_000..=Timestamp::MAX_SECONDS,
+            -1_000_i64..=1_000,
+            Timestamp::MIN_SECONDS..=Timestamp::MAX_SECONDS,
+        ]
+    }
+
+    fn timestamp_nanos() -> impl proptest::strategy::Strategy<Value = i32> {
+        use proptest::prelude::*;
+        prop_oneof![
+            Just(Timestamp::MIN_NANOS),
+            Just(Timestamp::MAX_NANOS),
+            Timestamp::MIN_NANOS..=Timestamp::MAX_NANOS,
+        ]
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn chrono_roundtrip_property(seconds in timestamp_seconds(), nanos in timestamp_nanos()) {
+            let ts = Timestamp::new(seconds, nanos).unwrap();
+            let dt = chrono::DateTime::<chrono::Utc>::try_from(ts.clone()).unwrap();
+            proptest::prop_assert_eq!(dt.timestamp(), seconds);
+            proptest::prop_assert_eq!(dt.timestamp_subsec_nanos(), nanos as u32);
+            proptest::prop_assert_eq!(Timestamp::try_from(dt).unwrap(), ts);
+        }
+    }
 }
@@ -14,6 +14,7 @@
 
 use gcp_sdk_wkt::Any;
 use gcp_sdk_wkt::Duration;
+use gcp_sdk_wkt::Message;
 
 type Result = std::result::Result<(), Box<dyn std::error::Error>>;
 
@@ -25,16 +26,22 @@ pub struct TestOnly {
     pub filter: Option<String>,
 }
 
+impl Message for TestOnly {
+    fn typename() -> &'static str {
+        "wkt.test.TestOnly"
+    }
+}
+
 #[test]
 fn roundtrip_generic() -> Result {
     let input = TestOnly {
         parent: "parent".to_string(),
         ..Default::default()
     };
-    let any = Any::from(&input)?;
+    let any = Any::from_msg(&input)?;
     let json = serde_json::to_value(any)?;
     let any = serde_json::from_value::<Any>(json)?;
-    let output = any.try_into_message::<TestOnly>()?;
+    let output = any.to_msg::<TestOnly>()?;
     assert_eq!(input, output);
     Ok(())
 }
@@ -42,10 +49,10 @@ fn roundtrip_generic() -> Result {
 #[test]
 fn roundtrip_duration() -> Result {
     let input = Duration::new(12, 3456)?;
-    let any = Any::from(&input)?;
+    let any = Any::from_msg(&input)?;
     let json = serde_json::to_value(any)?;
     let any = serde_json::from_value::<Any>(json)?;
-    let output = any.try_into_message::<Duration>()?;
+    let output = any.to_msg::<Duration>()?;
     assert_eq!(input, output);
     Ok(())
 }
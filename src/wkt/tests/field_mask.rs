@@ -44,7 +44,19 @@ fn serialize_in_struct() -> Result {
     };
 
     let json = serde_json::to_value(input)?;
-    assert_eq!(json, json!({ "mask": {"paths": "f1,f2,f3"} }));
+    assert_eq!(json, json!({ "mask": "f1,f2,f3" }));
+
+    let input = Helper {
+        mask: Some(
+            FieldMask::default().set_paths(
+                ["labels", "replication.user_managed"]
+                    .map(str::to_string)
+                    .to_vec(),
+            ),
+        ),
+    };
+    let json = serde_json::to_value(input)?;
+    assert_eq!(json, json!({ "mask": "labels,replication.userManaged" }));
     Ok(())
 }
 
@@ -67,5 +79,18 @@ fn deserialize_in_struct() -> Result {
     };
     let got = serde_json::from_value::<Helper>(input)?;
     assert_eq!(want, got);
+
+    let input = json!({ "mask": "labels,replication.userManaged" });
+    let want = Helper {
+        mask: Some(
+            FieldMask::default().set_paths(
+                ["labels", "replication.user_managed"]
+                    .map(str::to_string)
+                    .to_vec(),
+            ),
+        ),
+    };
+    let got = serde_json::from_value::<Helper>(input)?;
+    assert_eq!(want, got);
     Ok(())
 }
@@ -0,0 +1,71 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use gcp_sdk_wkt::*;
+use serde_json::json;
+type Result = std::result::Result<(), Box<dyn std::error::Error>>;
+
+#[serde_with::serde_as]
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default, rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Helper {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_struct: Option<Struct>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_value: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_list: Option<ListValue>,
+    pub field_null: NullValue,
+    #[serde(skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub map_value: std::collections::HashMap<String, Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub repeated_value: Vec<Value>,
+}
+
+#[test]
+fn access() {
+    let s = Struct::new().set("a", 1).set("b", "x");
+    assert_eq!(s.get("a").and_then(Value::as_f64), Some(1.0));
+    let l = ListValue::from(vec![true, false]);
+    assert_eq!(l.get(1).and_then(Value::as_bool), Some(false));
+    assert_eq!(Value::default(), Value::Null);
+    assert_eq!(Value::from(NullValue), Value::Null);
+}
+
+#[test]
+fn serialize_in_struct() -> Result {
+    let input = Helper {
+        field_struct: Some(Struct::new().set("name", "zebra").set("legs", 4)),
+        field_value: Some(Value::from(vec!["a", "b"])),
+        field_list: Some(ListValue::from(vec![Value::from(1.5), Value::Null])),
+        field_null: NullValue,
+        map_value: [("k".to_string(), Value::from(true))].into(),
+        repeated_value: vec![Value::from("x"), Value::from(Struct::new())],
+    };
+    let json = serde_json::to_value(&input)?;
+    let want = json!({
+        "fieldStruct": {"name": "zebra", "legs": 4.0},
+        "fieldValue": ["a", "b"],
+        "fieldList": [1.5, null],
+        "fieldNull": null,
+        "mapValue": {"k": true},
+        "repeatedValue": ["x", {}],
+    });
+    assert_eq!(json, want);
+
+    let roundtrip = serde_json::from_value::<Helper>(json)?;
+    assert_eq!(input, roundtrip);
+    Ok(())
+}